@@ -0,0 +1,49 @@
+#!/usr/bin/env python3
+"""Stand-in controller for ExternalController.
+
+Reads one JSON request per line on stdin, holding the time step and the building
+view, and writes back one JSON object echoing the time step along with the
+target floor of each elevator.  Each elevator heads to the nearest
+destination floor of its passengers, then the nearest floor with people
+waiting, and otherwise stays where it is.
+"""
+import json
+import sys
+
+
+def nearest(floor_on, floors):
+    floors = [f for f in floors if f != floor_on]
+    if not floors:
+        return None
+    return min(floors, key=lambda f: abs(f - floor_on))
+
+
+def decide(view):
+    waiting_floors = [
+        i for i, floor in enumerate(view["floors"]) if floor["num_people_waiting"] > 0
+    ]
+    targets = []
+    for car in view["elevators"]:
+        target = nearest(car["floor_on"], car["car_calls"])
+        if target is None:
+            target = nearest(car["floor_on"], waiting_floors)
+        if target is None:
+            target = car["floor_on"]
+        targets.append(target)
+    return targets
+
+
+def main():
+    for line in sys.stdin:
+        if not line.strip():
+            continue
+        request = json.loads(line)
+        response = {
+            "time_step": request["time_step"],
+            "targets": decide(request["view"]),
+        }
+        print(json.dumps(response), flush=True)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,325 @@
+//Import standard/imported modules
+use std::env;
+use std::io;
+use std::path::PathBuf;
+use std::process;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+//Import source modules
+use elevate_lib::building::Building;
+use elevate_lib::controller::ElevatorController;
+use elevate_lib::export::SvgExporter;
+use elevate_lib::metrics::MetricsRecorder;
+use elevate_lib::registry::{ControllerParams, ControllerRegistry};
+use elevate_lib::replay::{Replay, ReplayLog};
+use elevate_lib::scenario::{Scenario, ScenarioConfig};
+use elevate_lib::simulator::Simulator;
+use elevate_lib::trajectory::TrajectoryRecorder;
+
+//Constant representing the usage message of the binary
+const USAGE: &str = "Usage: elevate [OPTIONS]
+
+Run an elevator simulation and report its metrics.
+
+Options:
+  --scenario <FILE>       Load the scenario from a JSON file (default: built-in scenario)
+  --controller <NAME>     Pick the controller by name (default: nearest)
+  --param <KEY=VALUE>     Set a numeric controller parameter, may be repeated
+  --steps <N>             Override the number of time steps in the scenario
+  --seed <SEED>           Override the seed (default: the scenario's first seed)
+  --output <FORMAT>       Print a final summary, or stream per-step metrics as csv or
+                          jsonl (default: summary)
+  --render                Draw the building to stderr after each time step
+  --export <FILE>         Export the run as an animated SVG image, or an HTML page if
+                          the file ends in .html
+  --trajectory <FILE>     Export the elevators' trajectories as an SVG diagram, or as
+                          data if the file ends in .csv or .json
+  --record <FILE>         Record the run to a replay file, which is written even if
+                          the run fails
+  --replay <FILE>         Play back a replay file instead of running a controller,
+                          ignoring --scenario, --controller, --param, and --seed
+  --list-controllers      List the registered controllers and exit
+  -h, --help              Print this message and exit";
+
+/// # `OutputFormat` enum
+///
+/// An `OutputFormat` decides what the binary prints to stdout.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum OutputFormat {
+    Summary,
+    Csv,
+    Jsonl
+}
+
+/// # `Args` struct
+///
+/// The parsed command-line arguments of the binary.
+struct Args {
+    scenario: Option<PathBuf>,
+    controller: String,
+    params: ControllerParams,
+    steps: Option<usize>,
+    seed: Option<u64>,
+    output: OutputFormat,
+    render: bool,
+    export: Option<PathBuf>,
+    trajectory: Option<PathBuf>,
+    record: Option<PathBuf>,
+    replay: Option<PathBuf>,
+    list_controllers: bool,
+    help: bool
+}
+
+/// Parse the command-line arguments, excluding the program name.  Returns an error
+/// describing the problem if the arguments are invalid.
+fn parse_args(raw_args: &[String]) -> Result<Args, String> {
+    //Initialize the default arguments
+    let mut args: Args = Args {
+        scenario: None,
+        controller: String::from("nearest"),
+        params: ControllerParams::new(),
+        steps: None,
+        seed: None,
+        output: OutputFormat::Summary,
+        render: false,
+        export: None,
+        trajectory: None,
+        record: None,
+        replay: None,
+        list_controllers: false,
+        help: false
+    };
+
+    //Loop through the arguments
+    let mut iter = raw_args.iter();
+    while let Some(arg) = iter.next() {
+        //Get the value following an option which takes one
+        let mut get_value = |name: &str| -> Result<String, String> {
+            iter.next().cloned().ok_or_else(|| format!("{} requires a value", name))
+        };
+
+        //Parse the argument
+        match arg.as_str() {
+            "--scenario" => args.scenario = Some(PathBuf::from(get_value(arg)?)),
+            "--controller" => args.controller = get_value(arg)?,
+            "--param" => {
+                let param: String = get_value(arg)?;
+                let (key, value) = param.split_once('=')
+                    .ok_or_else(|| format!("--param expects KEY=VALUE, got {:?}", param))?;
+                let value: f64 = value.parse::<f64>()
+                    .map_err(|_| format!("--param {} expects a number, got {:?}", key, value))?;
+                args.params = args.params.with(key, value);
+            },
+            "--steps" => {
+                let steps: String = get_value(arg)?;
+                args.steps = Some(steps.parse::<usize>()
+                    .map_err(|_| format!("--steps expects a whole number, got {:?}", steps))?);
+            },
+            "--seed" => {
+                let seed: String = get_value(arg)?;
+                args.seed = Some(seed.parse::<u64>()
+                    .map_err(|_| format!("--seed expects a whole number, got {:?}", seed))?);
+            },
+            "--output" => {
+                args.output = match get_value(arg)?.as_str() {
+                    "summary" => OutputFormat::Summary,
+                    "csv" => OutputFormat::Csv,
+                    "jsonl" => OutputFormat::Jsonl,
+                    other => return Err(format!("unknown output format {:?}", other))
+                };
+            },
+            "--render" => args.render = true,
+            "--export" => args.export = Some(PathBuf::from(get_value(arg)?)),
+            "--trajectory" => args.trajectory = Some(PathBuf::from(get_value(arg)?)),
+            "--record" => args.record = Some(PathBuf::from(get_value(arg)?)),
+            "--replay" => args.replay = Some(PathBuf::from(get_value(arg)?)),
+            "--list-controllers" => args.list_controllers = true,
+            "-h" | "--help" => args.help = true,
+            other => return Err(format!("unknown argument {:?}", other))
+        }
+    }
+
+    //Return the parsed arguments
+    Ok(args)
+}
+
+/// # `Runner` enum
+///
+/// A `Runner` steps the simulation, either by running a simulator while optionally
+/// recording a replay, or by playing back a replay.
+enum Runner {
+    Simulator(Box<Simulator>, Option<Box<ReplayLog>>),
+    Replay(Box<Replay>)
+}
+
+//Implement the Runner interface
+impl Runner {
+    /// Run a single time step of the simulation
+    fn step(&mut self, rng: &mut StdRng) -> Result<(), String> {
+        match self {
+            Runner::Simulator(simulator, None) => simulator.step(rng)
+                .map_err(|violation| violation.to_string()),
+            Runner::Simulator(simulator, Some(log)) => log.record_step(simulator, rng)
+                .map_err(|violation| violation.to_string()),
+            Runner::Replay(replay) => {
+                if !replay.step_forward()? {
+                    return Err(String::from("replay ended"));
+                }
+                match &replay.last_violation {
+                    Some(violation) => Err(violation.to_string()),
+                    None => Ok(())
+                }
+            }
+        }
+    }
+
+    /// Immutably borrow the building being simulated
+    fn get_building(&self) -> &Building {
+        match self {
+            Runner::Simulator(simulator, _) => &simulator.building,
+            Runner::Replay(replay) => &replay.building
+        }
+    }
+}
+
+/// Add the custom columns reported by the binary alongside the built-in columns
+fn add_custom_columns(recorder: &mut MetricsRecorder) {
+    recorder.add_column("arrived", Box::new(|building: &Building| building.tot_arrived as f64));
+    recorder.add_column("departed", Box::new(|building: &Building| building.tot_departed as f64));
+    recorder.add_column("rejected", Box::new(|building: &Building| building.tot_rejected as f64));
+    recorder.add_column("abandoned", Box::new(|building: &Building| building.get_num_abandoned() as f64));
+}
+
+/// Run the simulation given the parsed arguments, and report its metrics
+fn run(args: &Args) -> Result<(), String> {
+    //Handle the informational options
+    let registry: ControllerRegistry = ControllerRegistry::new();
+    if args.help {
+        println!("{}", USAGE);
+        return Ok(());
+    }
+    if args.list_controllers {
+        for name in registry.get_names() {
+            println!("{}", name);
+        }
+        return Ok(());
+    }
+
+    let (mut runner, controller_name, num_steps, seed): (Runner, &str, usize, u64) = match &args.replay {
+        //Play back the replay, whose log holds the building, limiting the steps to
+        //its length
+        Some(path) => {
+            let replay: Replay = Replay::from(ReplayLog::load(path)?)?;
+            let num_steps: usize = args.steps.map_or(replay.get_num_steps(), |steps| steps.min(replay.get_num_steps()));
+            let seed: u64 = replay.log.config.seeds.first().copied().unwrap_or(0_u64);
+            (Runner::Replay(Box::new(replay)), "replay", num_steps, seed)
+        },
+
+        //Load the scenario and apply the overrides, then build the controller,
+        //recording it if requested
+        None => {
+            let config: ScenarioConfig = match &args.scenario {
+                Some(path) => ScenarioConfig::load(path)?,
+                None => ScenarioConfig::default()
+            };
+            let scenario: Scenario = Scenario::from_config(&config)?;
+            let seed: u64 = args.seed.or(scenario.seeds.first().copied()).unwrap_or(0_u64);
+            let controller: Box<dyn ElevatorController> = registry
+                .build(&args.controller, &scenario.building, &args.params)
+                .ok_or_else(|| format!(
+                    "unknown controller {:?}, expected one of: {}",
+                    args.controller,
+                    registry.get_names().join(", ")
+                ))?;
+            let log: Option<Box<ReplayLog>> = args.record.as_ref().map(|_| Box::new(ReplayLog::from(config.clone())));
+            let simulator: Simulator = Simulator::from(scenario.building.clone(), controller);
+            (Runner::Simulator(Box::new(simulator), log), args.controller.as_str(), args.steps.unwrap_or(scenario.num_steps), seed)
+        }
+    };
+
+    //Initialize the metrics recorder
+    let mut recorder: MetricsRecorder = MetricsRecorder::from(runner.get_building());
+    add_custom_columns(&mut recorder);
+    let mut exporter: Option<SvgExporter> = args.export.as_ref()
+        .map(|_| SvgExporter::from(runner.get_building(), 0.1_f64));
+    let mut trajectory_recorder: Option<TrajectoryRecorder> = args.trajectory.as_ref()
+        .map(|_| TrajectoryRecorder::from(runner.get_building()));
+    let mut stdout = io::stdout().lock();
+
+    //Run the simulation, streaming each step's metrics as requested
+    let mut rng: StdRng = StdRng::seed_from_u64(seed);
+    let mut result: Result<(), String> = Ok(());
+    for _ in 0..num_steps {
+        result = runner.step(&mut rng);
+        if result.is_err() {
+            break;
+        }
+        match args.output {
+            OutputFormat::Summary => Ok(()),
+            OutputFormat::Csv => {
+                recorder.sample(runner.get_building());
+                recorder.flush_csv(&mut stdout)
+            },
+            OutputFormat::Jsonl => {
+                recorder.sample(runner.get_building());
+                recorder.flush_jsonl(&mut stdout)
+            }
+        }.map_err(|err| format!("cannot write metrics: {}", err))?;
+        if let Some(exporter) = exporter.as_mut() {
+            exporter.sample(runner.get_building());
+        }
+        if let Some(trajectory_recorder) = trajectory_recorder.as_mut() {
+            trajectory_recorder.sample(runner.get_building());
+        }
+        if args.render {
+            eprintln!("{}\n", runner.get_building());
+        }
+    }
+
+    //Save the replay, including the failing step if the run failed
+    if let (Runner::Simulator(_, Some(log)), Some(path)) = (&runner, &args.record) {
+        log.save(path)?;
+    }
+    if let (Some(exporter), Some(path)) = (&exporter, &args.export) {
+        exporter.save(path).map_err(|err| format!("cannot write export {}: {}", path.display(), err))?;
+    }
+    if let (Some(trajectory_recorder), Some(path)) = (&trajectory_recorder, &args.trajectory) {
+        trajectory_recorder.save(path).map_err(|err| format!("cannot write trajectories {}: {}", path.display(), err))?;
+    }
+    result?;
+
+    //Print the summary
+    if args.output == OutputFormat::Summary {
+        let building: &Building = runner.get_building();
+        println!("Controller:\t\t{}", controller_name);
+        println!("Time steps:\t\t{}", num_steps);
+        println!("Seed:\t\t\t{}", seed);
+        println!("{}", building);
+        println!("People arrived:\t\t{}", building.tot_arrived);
+        println!("People departed:\t{}", building.tot_departed);
+        println!("People rejected:\t{}", building.tot_rejected);
+        println!("People in building:\t{}", building.get_num_people_in_building());
+    }
+    Ok(())
+}
+
+/// Run the binary, printing any error with the usage message and exiting with a
+/// nonzero status code
+fn main() {
+    //Parse the arguments, printing the usage if they are invalid
+    let raw_args: Vec<String> = env::args().skip(1_usize).collect();
+    let args: Args = match parse_args(&raw_args) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("error: {}\n\n{}", err, USAGE);
+            process::exit(2_i32);
+        }
+    };
+
+    //Run the simulation
+    if let Err(err) = run(&args) {
+        eprintln!("error: {}", err);
+        process::exit(1_i32);
+    }
+}
\ No newline at end of file
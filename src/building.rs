@@ -1,19 +1,29 @@
 //Import external/standard modules
-use rand::Rng;
-use rand::distributions::Distribution;
-use statrs::distribution::{Poisson, Binomial};
+use rand::RngCore;
+use rand::distributions::{Distribution, Bernoulli, WeightedIndex};
+use statrs::distribution::{Binomial, Normal, Exp};
 
 //Import source modules
-use crate::person::Person;
+use crate::person::{Person, PERSON_MASS};
 use crate::people::People;
 use crate::floor::Floor;
 use crate::floors::Floors;
-use crate::elevator::Elevator;
+use crate::elevator::{Elevator, ElevatorStatus};
 use crate::elevators::Elevators;
+use crate::validation::{InvariantViolation, ViolationKind};
+use crate::emergency::{BuildingMode, EvacuationReport};
+use crate::view::BuildingView;
+use crate::command::ElevatorCommand;
+use crate::entrance::Entrance;
+use crate::forecast::DemandForecaster;
+use crate::outcome::{OutcomeSource, RngOutcomes, BoolOutcome, CountOutcome, ValueOutcome};
 
 //Constant representing the probability a person leaves the building during a time step
 const P_OUT: f64 = 0.05_f64;
 
+//Constant representing the default number of time steps the destination forecast covers
+const FORECAST_HORIZON: usize = 10_usize;
+
 //Constant representing the probability a person leaves a tip
 const P_TIP: f64 = 0.5_f64;
 
@@ -21,11 +31,46 @@ const P_TIP: f64 = 0.5_f64;
 const DST_TIP_TRIALS: u64 = 100_u64;
 const DST_TIP_SUCCESS: f64 = 0.5_f64;
 
+/// # `OverflowPolicy` enum
+///
+/// An `OverflowPolicy` decides what happens to people who cannot enter a floor because
+/// it is at capacity, whether they are arriving at the building or alighting from an
+/// elevator.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OverflowPolicy {
+    /// People who do not fit are rejected and removed from the building.  This is the
+    /// default policy.
+    Drop,
+    /// Arrivals who do not fit queue outside the building, and are admitted ahead of
+    /// new arrivals once there is room.  Passengers who cannot alight stay on the
+    /// elevator.
+    Queue,
+    /// Arrivals who do not fit are rejected, and the elevator is blocked from
+    /// unloading passengers who do not fit, so they stay on the elevator.  People
+    /// getting off are handled the same as under `Queue`, so the two policies only
+    /// differ for arrivals, who are rejected rather than queued.
+    Block
+}
+
 /// # `Building` struct
 ///
 /// A `Building` aggregates `Elevator`s and `Floor`s.  It also tracks the everage
 /// energy usage by the elevators, and the average wait time among the people on
-/// the building's floors and elevators.  It randomly generates arrivals.
+/// the building's floors and elevators.  It randomly generates arrivals, and counts
+/// the trips abandoned by people who balk, renege, or take the stairs.  The number of
+/// arrivals during a time step can instead be given when beginning the step, such as
+/// when a `Campus` splits its arrivals across its buildings.
+///
+/// People arrive through the building's entrances, each an `Entrance` on its own floor
+/// with its own arrival process, and leave the building through an entrance picked in
+/// proportion to the entrances' arrival rates.  By default the first floor is the only
+/// entrance.  The first entrance floor is the lobby, which everyone is evacuated to
+/// during an emergency evacuation.  Floors are named by their index by default, and
+/// can be relabelled to place the lobby above basement levels.  Each time step the
+/// building forecasts the probability that each floor becomes a destination floor for
+/// an elevator within its `forecast_horizon`, for controllers to plan with.  If it has
+/// a `demand_forecaster`, then the building trains it on every time step, and
+/// forecasts the demand on each floor from the learned traffic by time of day.
 #[derive(Clone)]
 pub struct Building {
     pub elevators: Vec<Elevator>,
@@ -33,10 +78,38 @@ pub struct Building {
     pub avg_energy: f64,
     pub avg_wait_time: f64,
     pub tot_tips: f64,
+    pub tot_balked: usize,
+    pub tot_reneged: usize,
+    pub tot_stairs: usize,
+    pub tot_arrived: usize,
+    pub tot_departed: usize,
+    pub tot_rejected: usize,
+    pub overflow_policy: OverflowPolicy,
+    pub outside_queue: Vec<Person>,
+    pub time_step: usize,
+    pub debug: bool,
+    pub tot_breakdowns: usize,
+    pub mode: BuildingMode,
+    pub evacuation_report: Option<EvacuationReport>,
+    pub forecast_horizon: usize,
+    pub demand_forecaster: Option<DemandForecaster>,
     wait_time_denom: usize,
-    p_in: f64,
-    dst_in: Poisson,
-    dst_tip: Binomial
+    entrances: Vec<Entrance>,
+    dst_entrance: Option<WeightedIndex<f64>>,
+    dest_forecast: Vec<f64>,
+    dst_tip: Binomial,
+    mass_mean: f64,
+    dst_mass: Option<Normal>,
+    p_cargo: f64,
+    dst_cargo: Bernoulli,
+    cargo_mass: f64,
+    cargo_volume: f64,
+    balk_threshold: Option<usize>,
+    dst_patience: Option<Exp>,
+    max_stairs: usize,
+    dst_breakdown: Option<Bernoulli>,
+    dst_repair: Option<Exp>,
+    maintenance_schedule: Vec<(usize, usize, usize)>
 }
 
 /// # `Building` type implementation
@@ -74,8 +147,9 @@ impl Building {
         //Initialize the Floors
         let floors: Vec<Floor> = {
             let mut tmp_floors: Vec<Floor> = Vec::new();
-            for _ in 0_usize..num_floors {
-                let tmp_floor: Floor = Floor::new(floor_capacity);
+            for i in 0_usize..num_floors {
+                let mut tmp_floor: Floor = Floor::new(floor_capacity);
+                tmp_floor.name = i.to_string();
                 tmp_floors.push(tmp_floor);
             }
             tmp_floors
@@ -93,9 +167,6 @@ impl Building {
             tmp_elevators
         };
     
-        //Initialize the arrival probability distribution
-        let dst_in = Poisson::new(p_in).unwrap();
-    
         //Initialize and return the Building
         Building {
             floors: floors,
@@ -103,70 +174,990 @@ impl Building {
             avg_energy: 0_f64,
             avg_wait_time: 0_f64,
             wait_time_denom: 0_usize,
+            entrances: vec![Entrance::from(0_usize, p_in)],
+            dst_entrance: None,
+            dest_forecast: vec![0_f64; num_floors],
             tot_tips: 0_f64,
-            p_in: p_in,
-            dst_in: dst_in,
-            dst_tip: Binomial::new(DST_TIP_SUCCESS, DST_TIP_TRIALS).unwrap()
+            tot_balked: 0_usize,
+            tot_reneged: 0_usize,
+            tot_stairs: 0_usize,
+            tot_arrived: 0_usize,
+            tot_departed: 0_usize,
+            tot_rejected: 0_usize,
+            overflow_policy: OverflowPolicy::Drop,
+            outside_queue: Vec::new(),
+            time_step: 0_usize,
+            debug: false,
+            tot_breakdowns: 0_usize,
+            mode: BuildingMode::Normal,
+            evacuation_report: None,
+            forecast_horizon: FORECAST_HORIZON,
+            demand_forecaster: None,
+            dst_tip: Binomial::new(DST_TIP_SUCCESS, DST_TIP_TRIALS).unwrap(),
+            mass_mean: PERSON_MASS,
+            dst_mass: None,
+            p_cargo: 0.0_f64,
+            dst_cargo: Bernoulli::new(0.0_f64).unwrap(),
+            cargo_mass: 0.0_f64,
+            cargo_volume: 0.0_f64,
+            balk_threshold: None,
+            dst_patience: None,
+            max_stairs: 0_usize,
+            dst_breakdown: None,
+            dst_repair: None,
+            maintenance_schedule: Vec::new()
         }
     }
 
-    /// Calculate the probability that each floor becomes a destination floor for an elevator
-    /// during the next time step.  If the floor currently is a destination floor, then return
-    /// `1_f64`.
-    pub fn update_dest_probabilities(&mut self) {
-        //Get the number of floors in the building
-        let num_floors: usize = self.floors.len() as usize;
+    /// Update the reliability of the building's elevators given the mean number of time
+    /// steps between failures of an elevator, and the mean number of time steps it takes
+    /// to repair an elevator once it breaks down.  If the mean time between failures is
+    /// `None`, then elevators never break down, which is the default, and the mean
+    /// repair time is ignored.  Returns an error describing the problem, leaving the
+    /// reliability unchanged, if either mean is not positive and finite.
+    pub fn update_reliability(&mut self, mtbf: Option<f64>, mean_repair_time: f64) -> Result<(), String> {
+        //If there is no mean time between failures, then elevators never break down
+        let mtbf: f64 = match mtbf {
+            Some(mtbf) => mtbf,
+            None => {
+                self.dst_breakdown = None;
+                self.dst_repair = None;
+                return Ok(());
+            }
+        };
 
-        //Get the destination floors across each elevator
-        let dest_floors: Vec<usize> = self.elevators.get_dest_floors();
+        //Validate the means, then initialize the breakdown and repair distributions
+        if !mtbf.is_finite() || mtbf <= 0.0_f64 {
+            return Err(format!("mtbf must be positive, got {}", mtbf));
+        }
+        if !mean_repair_time.is_finite() || mean_repair_time <= 0.0_f64 {
+            return Err(format!("mean_repair_time must be positive, got {}", mean_repair_time));
+        }
+        let dst_breakdown: Bernoulli = Bernoulli::new((1_f64 / mtbf).min(1_f64)).map_err(|e| e.to_string())?;
+        let dst_repair: Exp = Exp::new(1_f64 / mean_repair_time).map_err(|e| e.to_string())?;
+        self.dst_breakdown = Some(dst_breakdown);
+        self.dst_repair = Some(dst_repair);
+        Ok(())
+    }
 
-        //Loop through the floors
-        for (i, floor) in self.floors.iter_mut().enumerate() {
-            //Initialize an f64 for this floor's probability
-            let dest_probability: f64 = if i == 0 {
-                //If this is the first floor, then calculate the prob
-                //based on arrival probability only
-                let people_waiting: f64 = {
-                    let waiting: f64 = if floor.are_people_waiting() { 1_f64 } else { 0_f64 };
-                    let going: f64 = if dest_floors.contains(&i) { 1_f64 } else { 0_f64 };
-                    if waiting > going { waiting } else { going }
-                };
-                let p_in: f64 = self.p_in * ((num_floors as f64 - 1_f64)/(num_floors as f64));
-                if people_waiting > p_in { people_waiting } else { p_in }
+    /// Schedule maintenance for the elevator at the given index, starting at the given
+    /// time step and lasting for the given number of time steps.
+    pub fn schedule_maintenance(&mut self, elevator_index: usize, start_step: usize, duration: usize) {
+        self.maintenance_schedule.push((elevator_index, start_step, duration));
+    }
+
+    /// Put the elevator at the given index into or out of independent service.  An
+    /// elevator in independent service is parked on its current floor, and commands
+    /// for it are rejected, so it is not dispatched by an `ElevatorController`.
+    /// Elevators which are out of service are left as is.
+    pub fn update_independent_service(&mut self, elevator_index: usize, independent: bool) {
+        let elevator: &mut Elevator = &mut self.elevators[elevator_index];
+        if elevator.is_out_of_service() {
+            return;
+        }
+        elevator.status = if independent {
+            ElevatorStatus::IndependentService
+        } else {
+            ElevatorStatus::InService
+        };
+    }
+
+    /// Take the elevator at the given index out of service given its new status, and
+    /// evacuate its passengers onto the floor it is on, which is always the nearest
+    /// floor since elevators move one floor per time step.  Anyone who does not fit on
+    /// the floor is rejected.
+    pub fn take_elevator_out_of_service(&mut self, elevator_index: usize, status: ElevatorStatus) {
+        let floor_index: usize = self.elevators[elevator_index].floor_on;
+        let people_evacuated: Vec<Person> = self.elevators[elevator_index].take_out_of_service(status);
+        let people_rejected: Vec<Person> = self.floors[floor_index].try_extend(people_evacuated);
+        self.tot_rejected += people_rejected.len();
+    }
+
+    /// Randomly generate whether each elevator which is not already out of service breaks
+    /// down during this time step, and if so take it out of service for a randomly
+    /// generated repair time.  The outcomes are decided by the given `OutcomeSource`.
+    pub fn gen_elevator_breakdowns(&mut self, outcomes: &mut dyn OutcomeSource) {
+        //If elevators never break down, then return early
+        let (dst_breakdown, dst_repair): (Bernoulli, Exp) = match (&self.dst_breakdown, &self.dst_repair) {
+            (Some(dst_breakdown), Some(dst_repair)) => (*dst_breakdown, *dst_repair),
+            _ => return
+        };
+
+        //Loop through the elevators and randomly break them down
+        for i in 0..self.elevators.len() {
+            if self.elevators[i].is_out_of_service() ||
+                !outcomes.gen_bool(BoolOutcome::Breakdown, &mut |rng| dst_breakdown.sample(rng)) {
+                continue;
+            }
+            let repair_time: usize = outcomes.gen_count(CountOutcome::RepairTime, &mut |rng| {
+                (dst_repair.sample(rng).ceil() as usize).max(1_usize)
+            });
+            self.take_elevator_out_of_service(i, ElevatorStatus::BrokenDown(repair_time));
+            self.tot_breakdowns += 1_usize;
+        }
+    }
+
+    /// Update the statuses of the building's elevators, counting down their maintenance
+    /// and repair times, and starting any maintenance scheduled for this time step.
+    pub fn update_elevator_statuses(&mut self) {
+        //Count down the maintenance and repair times
+        self.elevators.update_statuses();
+
+        //Start any maintenance scheduled for this time step
+        let maintenance_starting: Vec<(usize, usize, usize)> = self.maintenance_schedule.iter()
+            .filter(|(_, start_step, _)| *start_step == self.time_step)
+            .cloned()
+            .collect();
+        for (elevator_index, _, duration) in maintenance_starting.into_iter() {
+            if elevator_index >= self.elevators.len() || self.elevators[elevator_index].is_out_of_service() {
+                continue;
+            }
+            self.take_elevator_out_of_service(elevator_index, ElevatorStatus::Maintenance(duration));
+        }
+    }
+
+    /// Update the distribution of the mass (in kg) of the people arriving at the building
+    /// given its mean and standard deviation.  If the standard deviation is not positive,
+    /// then every arrival has the mean mass.  By default every arrival has `PERSON_MASS`.
+    /// Returns an error describing the problem, leaving the distribution unchanged, if
+    /// the mean is not positive and finite, or if the standard deviation is not a number.
+    pub fn update_arrival_mass(&mut self, mass_mean: f64, mass_std_dev: f64) -> Result<(), String> {
+        //Validate the mean and standard deviation
+        if !mass_mean.is_finite() || mass_mean <= 0.0_f64 {
+            return Err(format!("mass_mean must be positive, got {}", mass_mean));
+        }
+        if mass_std_dev.is_nan() {
+            return Err(String::from("mass_std_dev must be a number"));
+        }
+
+        //Update the mass distribution
+        let dst_mass: Option<Normal> = if mass_std_dev > 0.0_f64 {
+            Some(Normal::new(mass_mean, mass_std_dev).map_err(|e| e.to_string())?)
+        } else {
+            None
+        };
+        self.mass_mean = mass_mean;
+        self.dst_mass = dst_mass;
+        Ok(())
+    }
+
+    /// Update the probability that a person arriving at the building brings along
+    /// cargo such as luggage, a cart, or a hospital bed, as well as the mass (in kg)
+    /// and volume (in cubic meters) of the cargo.  By default no one brings cargo.
+    /// Returns an error describing the problem, leaving the cargo unchanged, if the
+    /// probability is outside [0, 1], or if the mass or volume is negative or not finite.
+    pub fn update_arrival_cargo(&mut self, p_cargo: f64, cargo_mass: f64, cargo_volume: f64) -> Result<(), String> {
+        //Validate the cargo's mass and volume
+        if !cargo_mass.is_finite() || cargo_mass < 0.0_f64 {
+            return Err(format!("cargo_mass must be non-negative, got {}", cargo_mass));
+        }
+        if !cargo_volume.is_finite() || cargo_volume < 0.0_f64 {
+            return Err(format!("cargo_volume must be non-negative, got {}", cargo_volume));
+        }
+
+        //Update the cargo distribution
+        self.dst_cargo = Bernoulli::new(p_cargo).map_err(|e| e.to_string())?;
+        self.p_cargo = p_cargo;
+        self.cargo_mass = cargo_mass;
+        self.cargo_volume = cargo_volume;
+        Ok(())
+    }
+
+    /// Update the abandonment behavior of the people arriving at the building given
+    /// the queue length beyond which arrivals balk, the mean number of time steps people
+    /// are willing to wait before reneging, and the maximum number of floors people are
+    /// willing to travel using the stairs.  By default no one balks, reneges, or takes
+    /// the stairs.  Returns an error describing the problem, leaving the abandonment
+    /// behavior unchanged, if the mean patience is not positive and finite.
+    pub fn update_abandonment(&mut self, balk_threshold: Option<usize>, mean_patience: Option<f64>,
+                              max_stairs: usize) -> Result<(), String> {
+        //Validate the mean patience and initialize the patience distribution
+        let dst_patience: Option<Exp> = match mean_patience {
+            Some(mean_patience) => {
+                if !mean_patience.is_finite() || mean_patience <= 0.0_f64 {
+                    return Err(format!("mean_patience must be positive, got {}", mean_patience));
+                }
+                Some(Exp::new(1_f64 / mean_patience).map_err(|e| e.to_string())?)
+            },
+            None => None
+        };
+
+        //Update the abandonment behavior
+        self.balk_threshold = balk_threshold;
+        self.dst_patience = dst_patience;
+        self.max_stairs = max_stairs;
+        Ok(())
+    }
+
+    /// Update the building's entrances given pairs of each entrance floor's index and
+    /// its expected number of arrivals per time step, replacing the building's
+    /// arrival rate.  People exit through each entrance in proportion to its arrival
+    /// rate, or evenly if no entrance expects arrivals.  The first entrance floor is
+    /// the lobby.  Resets the entrances' metrics.  Returns an error describing the
+    /// problem, leaving the entrances unchanged, if there are no entrances, if a floor
+    /// is out of bounds or repeated, or if an arrival rate is negative or not finite.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// //A street lobby on floor 2, and a parking garage on floor 0 used half as often
+    /// my_building.update_entrances(&[(2_usize, 1.0_f64), (0_usize, 0.5_f64)]).unwrap();
+    /// ```
+    pub fn update_entrances(&mut self, entrances: &[(usize, f64)]) -> Result<(), String> {
+        //Validate the entrances
+        if entrances.is_empty() {
+            return Err(String::from("a building needs at least one entrance floor"));
+        }
+        for (i, (floor_index, p_in)) in entrances.iter().enumerate() {
+            if *floor_index >= self.floors.len() {
+                return Err(format!("entrance floor {} is out of bounds", floor_index));
+            }
+            if entrances[..i].iter().any(|(other_floor_index, _)| other_floor_index == floor_index) {
+                return Err(format!("entrance floor {} is repeated", floor_index));
+            }
+            if !p_in.is_finite() || *p_in < 0.0_f64 {
+                return Err(format!("entrance floor {} has an invalid arrival rate {}", floor_index, p_in));
+            }
+        }
+
+        //Update the entrances, and the distribution of the entrances people exit through
+        self.entrances = entrances.iter()
+            .map(|(floor_index, p_in)| Entrance::from(*floor_index, *p_in))
+            .collect();
+        self.dst_entrance = if entrances.len() > 1_usize {
+            WeightedIndex::new(self.get_exit_shares()).ok()
+        } else {
+            None
+        };
+        Ok(())
+    }
+
+    /// Get the share of the people leaving the building who exit through each entrance,
+    /// which is in proportion to the entrances' arrival rates, or even if no entrance
+    /// expects arrivals
+    fn get_exit_shares(&self) -> Vec<f64> {
+        let tot_p_in: f64 = self.get_p_in();
+        self.entrances.iter()
+            .map(|entrance| if tot_p_in > 0.0_f64 {
+                entrance.p_in / tot_p_in
             } else {
-                //If this is not the first floor, then calculate the
-                //prob based on the elevator's people and the floor's
-                //people and append it to the list
-                let people_waiting: f64 = {
-                    let waiting: f64 = if floor.are_people_waiting() { 1_f64 } else { 0_f64 };
-                    let going: f64 = if dest_floors.contains(&i) { 1_f64 } else { 0_f64 };
-                    if waiting > going { waiting } else { going }
-                };
-                let p_out: f64 = floor.get_p_out();
-                if people_waiting > p_out { people_waiting } else { p_out }
+                1_f64 / self.entrances.len() as f64
+            })
+            .collect()
+    }
+
+    /// Immutably borrow the building's entrances
+    pub fn get_entrances(&self) -> &[Entrance] {
+        &self.entrances
+    }
+
+    /// Get the indices of the building's entrance floors
+    pub fn get_entrance_floors(&self) -> Vec<usize> {
+        self.entrances.iter().map(|entrance| entrance.floor_index).collect()
+    }
+
+    /// Get the index of the building's lobby, which is its first entrance floor
+    pub fn get_lobby_floor(&self) -> usize {
+        self.entrances[0].floor_index
+    }
+
+    /// Get the expected number of arrivals per time step across the building's
+    /// entrances
+    pub fn get_p_in(&self) -> f64 {
+        self.entrances.iter().map(|entrance| entrance.p_in).sum()
+    }
+
+    /// Relabel the building's floors with consecutive signed labels starting from the
+    /// given label for the bottom floor, so that basement levels are labelled below
+    /// zero.  Floor indices are unchanged.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// //Two parking levels labelled -2 and -1 below the ground floor labelled 0
+    /// my_building.update_floor_labels(-2_i32);
+    /// ```
+    pub fn update_floor_labels(&mut self, lowest_label: i32) {
+        for (i, floor) in self.floors.iter_mut().enumerate() {
+            floor.name = (i64::from(lowest_label) + i as i64).to_string();
+        }
+    }
+
+    /// Get the index of the floor with the given name, if any
+    pub fn get_floor_index(&self, name: &str) -> Option<usize> {
+        self.floors.iter().position(|floor| floor.name == name)
+    }
+
+    /// Pick the index of an entrance at random in proportion to the entrances' arrival
+    /// rates, as the given kind of outcome
+    fn gen_entrance_index(&self, kind: CountOutcome, outcomes: &mut dyn OutcomeSource) -> usize {
+        match &self.dst_entrance {
+            Some(dst_entrance) => outcomes.gen_count(kind, &mut |rng| dst_entrance.sample(rng)),
+            None => 0_usize
+        }
+    }
+
+    /// Get the index of the entrance on the given floor, if any
+    fn get_entrance_index(&self, floor_index: usize) -> Option<usize> {
+        self.entrances.iter().position(|entrance| entrance.floor_index == floor_index)
+    }
+
+    /// Add people onto their entrance floors until each is at capacity, and return
+    /// anyone who did not fit.  Anyone whose floor is no longer an entrance floor is
+    /// moved to the lobby.
+    fn try_extend_entrance_floors(&mut self, people: Vec<Person>) -> Vec<Person> {
+        //Group the people by their entrance floor
+        let lobby_floor: usize = self.get_lobby_floor();
+        let mut people_per_floor: Vec<Vec<Person>> = vec![Vec::new(); self.floors.len()];
+        for mut pers in people.into_iter() {
+            if self.get_entrance_index(pers.floor_on).is_none() {
+                pers.floor_on = lobby_floor;
+            }
+            people_per_floor[pers.floor_on].push(pers);
+        }
+
+        //Add each group onto its floor, collecting anyone who does not fit
+        let mut people_rejected: Vec<Person> = Vec::new();
+        for (floor, people_entering) in self.floors.iter_mut().zip(people_per_floor) {
+            people_rejected.extend(floor.try_extend(people_entering));
+        }
+        people_rejected
+    }
+
+    /// Get the total number of trips abandoned by people who balked or reneged
+    pub fn get_num_abandoned(&self) -> usize {
+        self.tot_balked + self.tot_reneged
+    }
+
+    /// Get the number of wait times averaged into the building's average wait time,
+    /// which is the number of people who have gotten off an elevator.  Used to weigh
+    /// the building's average wait time when combining it with other buildings'.
+    pub fn get_num_wait_times(&self) -> usize {
+        self.wait_time_denom
+    }
+
+    /// Get the total number of people on the building's floors and elevators, which
+    /// excludes anyone queueing outside the building.
+    pub fn get_num_people_in_building(&self) -> usize {
+        let mut num_people: usize = 0_usize;
+        for floor in self.floors.iter() {
+            num_people += floor.get_num_people();
+        }
+        for elevator in self.elevators.iter() {
+            num_people += elevator.get_num_people();
+        }
+        num_people
+    }
+
+    /// Get the number of people accounted for by the building, that is, the people in
+    /// the building or queueing outside it, and the people counted as departed,
+    /// rejected, balked, or reneged.
+    fn get_num_accounted(&self) -> usize {
+        self.get_num_people_in_building() + self.outside_queue.len() +
+            self.tot_departed + self.tot_rejected + self.tot_balked + self.tot_reneged
+    }
+
+    /// Check that people are conserved, that is, everyone who ever arrived at the
+    /// building is still in the building, queueing outside it, or was counted as
+    /// departed, rejected, balked, or reneged.  Returns a bool which is true if so, and
+    /// false if not.
+    pub fn is_conserved(&self) -> bool {
+        self.get_num_accounted() == self.tot_arrived
+    }
+
+    /// Start a fire-service recall, overriding the active `ElevatorController`.  Every
+    /// elevator returns to the given recall floor without stopping, hall calls are
+    /// cancelled so no one boards, passengers get off at the recall floor, and no one
+    /// arrives at the building until the emergency ends.  Returns an error, leaving the
+    /// building unchanged, if the recall floor is out of bounds.
+    pub fn start_fire_recall(&mut self, recall_floor: usize) -> Result<(), String> {
+        //Validate the recall floor
+        if recall_floor >= self.floors.len() {
+            return Err(format!("recall floor {} is out of bounds", recall_floor));
+        }
+
+        //Direct every passenger to the recall floor
+        for elevator in self.elevators.iter_mut() {
+            for pers in elevator.people.iter_mut() {
+                pers.floor_to = recall_floor;
+            }
+        }
+
+        //Switch the building into fire-service recall mode
+        self.mode = BuildingMode::FireRecall { recall_floor: recall_floor };
+        Ok(())
+    }
+
+    /// Start an emergency evacuation, overriding the active `ElevatorController`.
+    /// Everyone on the building's floors and elevators is directed to the lobby
+    /// and marked as leaving, and the elevators are used to move them there as fast as
+    /// possible.  The progress of the evacuation is tracked in the building's
+    /// `evacuation_report`.
+    pub fn start_evacuation(&mut self) {
+        //Direct everyone in the building to the lobby
+        self.evacuate(self.get_lobby_floor());
+
+        //Switch the building into evacuation mode and start the report
+        self.mode = BuildingMode::Evacuation;
+        self.evacuation_report = Some(EvacuationReport::from(
+            self.time_step,
+            self.floors.iter().map(|floor| floor.name.clone()).collect()
+        ));
+    }
+
+    /// End any emergency, returning control of the elevators to the active
+    /// `ElevatorController`.
+    pub fn end_emergency(&mut self) {
+        self.mode = BuildingMode::Normal;
+    }
+
+    /// Update the elevators according to the building's emergency mode, in place of the
+    /// active `ElevatorController`.  During a fire-service recall every elevator heads
+    /// to the recall floor.  During an evacuation, elevators with passengers pick up
+    /// anyone waiting on their way to the lobby, and empty elevators head to the
+    /// nearest floor with people waiting that no other elevator is already heading to.
+    /// Does nothing in normal mode.
+    pub fn update_elevators_emergency(&mut self) {
+        //Decide each elevator's destination floor according to the mode
+        let mut elevator_decisions: Vec<usize> = Vec::new();
+        match self.mode {
+            BuildingMode::Normal => return,
+            BuildingMode::FireRecall { recall_floor } => {
+                for _ in self.elevators.iter() {
+                    elevator_decisions.push(recall_floor);
+                }
+            },
+            BuildingMode::Evacuation => {
+                //Get the floors other than the lobby with people waiting to be evacuated
+                let lobby_floor: usize = self.get_lobby_floor();
+                let wait_floors: Vec<usize> = (0_usize..self.floors.len())
+                    .filter(|i| *i != lobby_floor && self.floors[*i].are_people_waiting())
+                    .collect();
+
+                //Loop through the elevators and decide where each evacuates from
+                let mut floors_targeted: Vec<usize> = Vec::new();
+                for elevator in self.elevators.iter() {
+                    let decision: usize = if elevator.get_num_people() > 0_usize {
+                        //If carrying people, then stop for anyone waiting on the way
+                        //to the lobby if there is room, otherwise go to the lobby
+                        let wait_floor_on_way: Option<usize> = wait_floors.iter()
+                            .filter(|i| **i <= elevator.floor_on.max(lobby_floor) && **i >= elevator.floor_on.min(lobby_floor))
+                            .filter(|i| !floors_targeted.contains(*i))
+                            .min_by_key(|i| elevator.floor_on.abs_diff(**i))
+                            .cloned();
+                        match wait_floor_on_way {
+                            Some(wait_floor) if elevator.get_free_capacity() > 0_usize => wait_floor,
+                            _ => lobby_floor
+                        }
+                    } else {
+                        //If empty, then go to the nearest untargeted floor with people
+                        //waiting, preferring the higher floor on ties
+                        let nearest_wait_floor: Option<usize> = wait_floors.iter()
+                            .filter(|i| !floors_targeted.contains(*i))
+                            .min_by_key(|i| (elevator.floor_on.abs_diff(**i), usize::MAX - **i))
+                            .cloned();
+                        nearest_wait_floor.unwrap_or(lobby_floor)
+                    };
+                    if decision != lobby_floor {
+                        floors_targeted.push(decision);
+                    }
+                    elevator_decisions.push(decision);
+                }
+            }
+        }
+
+        //Loop through the decisions and update the elevators which are not out of service
+        for (i, decision) in elevator_decisions.iter().enumerate() {
+            if self.elevators[i].is_out_of_service() {
+                continue;
+            }
+            self.elevators[i].update_direction(*decision);
+            let _new_floor_index = self.elevators[i].update_floor();
+        }
+    }
+
+    /// Update the evacuation report, if an evacuation is in progress, marking any
+    /// newly cleared floors and whether the building has been cleared.
+    pub fn update_evacuation_report(&mut self) {
+        //If no evacuation is in progress, then return early
+        if self.mode != BuildingMode::Evacuation {
+            return;
+        }
+
+        //Count the people on the floors and elevators, and update the report
+        let num_people_per_floor: Vec<usize> = self.floors.get_num_people_per_floor();
+        let num_people_on_elevators: usize = self.elevators.iter().map(|elevator| elevator.get_num_people()).sum();
+        if let Some(evacuation_report) = self.evacuation_report.as_mut() {
+            evacuation_report.update(self.time_step, &num_people_per_floor, num_people_on_elevators);
+        }
+    }
+
+    /// Get an immutable `BuildingView` snapshot of the building, which is passed to an
+    /// `ElevatorController` to decide its `ElevatorCommand`s.
+    pub fn get_view(&self) -> BuildingView {
+        BuildingView::from(self)
+    }
+
+    /// Validate and apply the given elevator commands, moving each elevator at most one
+    /// floor.  Commands for elevators which do not exist or are unavailable, commands
+    /// to floors which do not exist, and any commands after the first for the same
+    /// elevator are rejected and returned.  Available elevators without a valid command
+    /// are held stopped on their current floor.
+    pub fn apply_elevator_commands(&mut self, commands: &[ElevatorCommand]) -> Vec<ElevatorCommand> {
+        //Initialize the commands to apply for each elevator and the rejected commands
+        let num_floors: usize = self.floors.len();
+        let mut elevator_commands: Vec<Option<ElevatorCommand>> = vec![None; self.elevators.len()];
+        let mut commands_rejected: Vec<ElevatorCommand> = Vec::new();
+
+        //Validate the commands
+        for command in commands.iter() {
+            //Reject the command if its elevator does not exist or is unavailable
+            let elevator_index: usize = command.get_elevator_index();
+            if elevator_index >= self.elevators.len() || !self.elevators[elevator_index].is_available() {
+                commands_rejected.push(*command);
+                continue;
+            }
+
+            //Reject the command if its floor does not exist
+            if let Some(floor_index) = command.get_floor_index() {
+                if floor_index >= num_floors {
+                    commands_rejected.push(*command);
+                    continue;
+                }
+            }
+
+            //Reject the command if the elevator already has one
+            if elevator_commands[elevator_index].is_some() {
+                commands_rejected.push(*command);
+                continue;
+            }
+            elevator_commands[elevator_index] = Some(*command);
+        }
+
+        //Loop through the available elevators and apply their commands
+        for (i, elevator) in self.elevators.iter_mut().enumerate() {
+            //Skip elevators which are unavailable, resetting their idle counters
+            if !elevator.is_available() {
+                elevator.update_idle(None, 0_usize);
+                continue;
+            }
+
+            //Hold elevators without a command stopped on their current floor
+            let command: ElevatorCommand = elevator_commands[i]
+                .unwrap_or(ElevatorCommand::Stop { elevator_index: i });
+
+            //Update the elevator's direction and floor according to the command
+            let (sleep_after, wake_delay): (Option<usize>, usize) = match command {
+                ElevatorCommand::GoToFloor { floor_index, .. } => {
+                    elevator.update_direction(floor_index);
+                    (None, 0_usize)
+                },
+                ElevatorCommand::Stop { .. } => {
+                    elevator.update_direction(elevator.floor_on);
+                    (None, 0_usize)
+                },
+                ElevatorCommand::OpenDoors { .. } => {
+                    elevator.wake();
+                    elevator.update_direction(elevator.floor_on);
+                    (None, 0_usize)
+                },
+                ElevatorCommand::Park { floor_index, sleep_after, wake_delay, .. } => {
+                    elevator.update_direction(floor_index);
+                    (sleep_after, wake_delay)
+                }
             };
+            let _new_floor_index = elevator.update_floor();
+
+            //Update the elevator's idle counter, putting it to sleep if parked long enough
+            elevator.update_idle(sleep_after, wake_delay);
+        }
+
+        //Return the rejected commands
+        commands_rejected
+    }
+
+    /// Check the structural invariants of the building: every elevator is within the
+    /// building and within its capacity and rated load, every floor is within its
+    /// capacity, every person is on the floor or elevator holding them and is going to
+    /// a floor within the building, and people are conserved.  Returns the first
+    /// violation found, if any.
+    pub fn validate(&self) -> Result<(), InvariantViolation> {
+        //Get the number of floors in the building
+        let num_floors: usize = self.floors.len();
+
+        //Check the elevators and floors
+        for (i, elevator) in self.elevators.iter().enumerate() {
+            if let Err(kind) = elevator.validate(i, num_floors) {
+                return Err(InvariantViolation { time_step: self.time_step, kind: kind });
+            }
+        }
+        for (i, floor) in self.floors.iter().enumerate() {
+            if let Err(kind) = floor.validate(i, num_floors) {
+                return Err(InvariantViolation { time_step: self.time_step, kind: kind });
+            }
+        }
+
+        //Check that people are conserved
+        if !self.is_conserved() {
+            return Err(InvariantViolation {
+                time_step: self.time_step,
+                kind: ViolationKind::PeopleNotConserved {
+                    num_accounted: self.get_num_accounted(),
+                    num_arrived: self.tot_arrived
+                }
+            });
+        }
+        Ok(())
+    }
+
+    /// Begin a time step, before the elevators are updated by an `ElevatorController`.
+    /// Updates the elevators' statuses and generates breakdowns, generates the people
+    /// arriving and leaving, removes the people abandoning the elevators, removes the
+    /// people leaving the building and collects their tips, and exchanges people
+    /// between the elevators and their floors.
+    pub fn begin_step(&mut self, rng: &mut dyn RngCore) {
+        self.begin_step_with_arrivals(None, rng);
+    }
+
+    /// Begin a time step as `begin_step` does, except that if a number of arrivals is
+    /// given, then it is split across the entrances instead of sampling each entrance's
+    /// arrivals.
+    pub fn begin_step_with_arrivals(&mut self, num_arrivals: Option<usize>, rng: &mut dyn RngCore) {
+        self.begin_step_with_outcomes(num_arrivals, &mut RngOutcomes::from(rng));
+    }
+
+    /// Begin a time step as `begin_step_with_arrivals` does, except that the random
+    /// outcomes of the step are decided by the given `OutcomeSource` rather than sampled
+    /// from an rng, such as when a `Replay` plays back the outcomes of a recorded run.
+    pub fn begin_step_with_outcomes(&mut self, num_arrivals: Option<usize>, outcomes: &mut dyn OutcomeSource) {
+        self.update_elevator_statuses();
+        self.gen_elevator_breakdowns(outcomes);
+        let _people_rejected: Vec<Person> = self.gen_people_arriving(num_arrivals, outcomes);
+        self.gen_people_leaving(outcomes);
+        self.update_people_abandoning();
+        self.flush_and_update_tips(outcomes);
+        self.exchange_people_on_elevator();
+    }
+
+    /// End a time step, after the elevators are updated by an `ElevatorController`.
+    /// Updates the average energy, the destination probabilities, and the wait times,
+    /// trains the demand forecaster, if any, then increments the time step and updates
+    /// the evacuation report, if any.  If the building is in debug mode, then its
+    /// invariants are validated and the first violation found is returned.
+    pub fn end_step(&mut self) -> Result<(), InvariantViolation> {
+        //Update the building's metrics
+        let energy_spent: f64 = self.elevators.get_energy_spent();
+        self.update_average_energy(self.time_step as i32, energy_spent);
+        self.update_dest_probabilities();
+        self.increment_wait_times();
+        if let Some(mut demand_forecaster) = self.demand_forecaster.take() {
+            demand_forecaster.observe(self);
+            self.demand_forecaster = Some(demand_forecaster);
+        }
+        self.time_step += 1_usize;
+        self.update_evacuation_report();
+
+        //If in debug mode, then validate the building
+        if self.debug {
+            return self.validate();
+        }
+        Ok(())
+    }
+
+    /// Calculate the probability that each floor becomes a destination floor for an elevator
+    /// during the next time step into each floor's `dest_prob`, and forecast the
+    /// probabilities over the building's `forecast_horizon` into its destination
+    /// forecast.
+    pub fn update_dest_probabilities(&mut self) {
+        //Update the floors' probabilities for the next time step
+        let dest_probabilities: Vec<f64> = self.forecast_dest_probabilities(1_usize);
+        for (floor, dest_probability) in self.floors.iter_mut().zip(dest_probabilities) {
             floor.dest_prob = dest_probability;
         }
+
+        //Update the forecast over the building's horizon
+        self.dest_forecast = self.forecast_dest_probabilities(self.forecast_horizon);
+    }
+
+    /// Get the building's destination forecast, which holds the probability that each
+    /// floor becomes a destination floor for an elevator within the building's
+    /// `forecast_horizon`, as of the end of the last time step.
+    pub fn get_dest_forecast(&self) -> &[f64] {
+        &self.dest_forecast
     }
 
-    /// Generate the people arriving by sampling the Poisson distribution to receive a number
-    /// of arrivals, and then instantiate that many people and append them to the first floor.
-    pub fn gen_people_arriving(&mut self, mut rng: &mut impl Rng) {
+    /// Forecast the expected number of trips starting on each floor over the given
+    /// number of time steps from the current time step, using the building's demand
+    /// forecaster.  Returns `None` if the building has no demand forecaster.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// my_building.demand_forecaster = Some(DemandForecaster::from(
+    ///     &my_building, 2400_usize, 24_usize, ForecastMethod::BucketAverage
+    /// ));
+    /// let demand: Option<Vec<f64>> = my_building.get_demand_forecast(100_usize);
+    /// ```
+    pub fn get_demand_forecast(&self, horizon: usize) -> Option<Vec<f64>> {
+        self.demand_forecaster.as_ref()
+            .map(|demand_forecaster| demand_forecaster.forecast_demand(self.time_step, horizon))
+    }
+
+    /// Forecast the probability that each floor becomes a destination floor for an
+    /// elevator within the given number of time steps.  A floor which already is a
+    /// destination floor, because people are waiting on it, an elevator's passenger is
+    /// going to it, or someone waiting elsewhere is going to it, has a probability of
+    /// `1_f64`.  Otherwise the probability combines, as independent events:
+    ///
+    /// - hall calls from people on the floor who leave according to their `p_out`, and
+    ///   exit the building from another floor
+    /// - hall calls from arrivals if the floor is an entrance floor, according to the
+    ///   entrance's arrival rate
+    /// - car calls from future arrivals, whose destinations are uniform across the
+    ///   floors, and from future leavers, who exit through an entrance picked in
+    ///   proportion to the entrances' arrival rates
+    ///
+    /// Arrivals are treated as Poisson processes, and are ignored during an emergency.
+    /// Takes linear time in the number of people, floors, and entrances.
+    pub fn forecast_dest_probabilities(&self, horizon: usize) -> Vec<f64> {
+        //Get the number of floors in the building and the horizon as f64s
+        let num_floors: f64 = self.floors.len() as f64;
+        let horizon: f64 = horizon as f64;
+
+        //Mark the floors which already are destination floors
+        let mut is_dest_floor: Vec<bool> = vec![false; self.floors.len()];
+        for elevator in self.elevators.iter() {
+            for dest_floor in elevator.get_dest_floors() {
+                is_dest_floor[dest_floor] = true;
+            }
+        }
+        for (i, floor) in self.floors.iter().enumerate() {
+            for dest_floor in floor.get_dest_floors() {
+                if dest_floor != i {
+                    is_dest_floor[i] = true;
+                    is_dest_floor[dest_floor] = true;
+                }
+            }
+        }
+
+        //Get the share of people leaving who exit through each entrance, and the
+        //expected number of people leaving each floor per time step
+        let tot_p_in: f64 = self.get_p_in();
+        let exit_shares: Vec<f64> = self.get_exit_shares();
+        let nums_leaving: Vec<f64> = self.floors.iter()
+            .map(|floor| floor.get_expected_num_leaving())
+            .collect();
+        let tot_num_leaving: f64 = nums_leaving.iter().sum();
+
+        //Calculate the expected number of calls to each floor per time step from
+        //arrivals and from people leaving via the entrances, starting from the car calls
+        //of arrivals spread evenly across the floors
+        let arrivals_active: bool = self.mode == BuildingMode::Normal;
+        let active_p_in: f64 = if arrivals_active { tot_p_in } else { 0_f64 };
+        let mut call_rates: Vec<f64> = vec![active_p_in / num_floors; self.floors.len()];
+        let mut exit_shares_per_floor: Vec<f64> = vec![0_f64; self.floors.len()];
+        for (entrance, exit_share) in self.entrances.iter().zip(exit_shares.iter()) {
+            let i: usize = entrance.floor_index;
+            exit_shares_per_floor[i] = *exit_share;
+
+            //Arrivals on the entrance floor call the elevator there, unless going to
+            //the entrance floor itself, which also receives no car calls from them
+            if arrivals_active {
+                call_rates[i] += entrance.p_in * (num_floors - 1_f64) / num_floors - entrance.p_in / num_floors;
+            }
+
+            //People leaving from other floors call the elevator to the entrance floor
+            call_rates[i] += (tot_num_leaving - nums_leaving[i]) * exit_share;
+        }
+
+        //Combine the calls into each floor's probability
+        self.floors.iter()
+            .enumerate()
+            .map(|(i, floor)| {
+                if is_dest_floor[i] {
+                    return 1_f64;
+                }
+                let p_no_hall_call: f64 = (1_f64 - floor.get_p_hall_call(1_f64 - exit_shares_per_floor[i])).powf(horizon);
+                1_f64 - p_no_hall_call * (-horizon * call_rates[i]).exp()
+            })
+            .collect()
+    }
+
+    /// Generate the people arriving by sampling each entrance's Poisson distribution to
+    /// receive its number of arrivals, and then instantiate that many people and append
+    /// them to the entrance's floor.  If a number of arrivals is given, then it is used
+    /// instead of sampling, and each arrival is assigned to an entrance in proportion
+    /// to the entrances' arrival rates.  Anyone queueing outside the building is
+    /// admitted first.  No one arrives while the building is in an emergency mode.
+    /// Returns the arrivals who were rejected because their entrance floor was at
+    /// capacity, which are also counted.  The outcomes are decided by the given
+    /// `OutcomeSource`.
+    pub fn gen_people_arriving(&mut self, num_arrivals: Option<usize>, outcomes: &mut dyn OutcomeSource) -> Vec<Person> {
+        //If there is an emergency, then no one arrives
+        if self.mode != BuildingMode::Normal {
+            return Vec::new();
+        }
+
+        //Admit anyone queueing outside the building who now fits
+        let people_queued: Vec<Person> = std::mem::take(&mut self.outside_queue);
+        self.outside_queue = self.try_extend_entrance_floors(people_queued);
+
         //Initialize a vector of Persons
         let mut arrivals: Vec<Person> = Vec::new();
 
-        //Loop until no new arrivals occur, for each arrival append a new person
-        for _ in 0_i32..self.dst_in.sample(&mut rng) as i32 {
-            let new_person: Person = Person::from(P_OUT, P_TIP, self.floors.len(), &mut rng);
-            arrivals.push(new_person);
+        //Get the number of people already queueing on each floor and outside, though
+        //only the entrance floors' queues are used
+        let mut queue_lengths: Vec<usize> = self.floors.iter()
+            .map(|floor| floor.get_num_people_waiting())
+            .collect();
+        for pers in self.outside_queue.iter() {
+            queue_lengths[pers.floor_on] += 1_usize;
         }
 
-        //Extend the first floor with the new arrivals
-        self.floors[0].extend(arrivals);
+        //Get the number of arrivals through each entrance
+        let arrivals_per_entrance: Vec<usize> = match num_arrivals {
+            Some(num_arrivals) => {
+                let mut tmp_arrivals_per_entrance: Vec<usize> = vec![0_usize; self.entrances.len()];
+                for _ in 0_usize..num_arrivals {
+                    tmp_arrivals_per_entrance[self.gen_entrance_index(CountOutcome::ArrivalEntrance, outcomes)] += 1_usize;
+                }
+                tmp_arrivals_per_entrance
+            },
+            None => self.entrances.iter()
+                .map(|entrance| outcomes.gen_count(CountOutcome::NumArrivals, &mut |rng| entrance.gen_num_arrivals(rng)))
+                .collect()
+        };
+
+        //Loop through the entrances, for each arrival append a new person
+        for (entrance_index, num_arrivals) in arrivals_per_entrance.into_iter().enumerate() {
+            let floor_index: usize = self.entrances[entrance_index].floor_index;
+            for _ in 0_usize..num_arrivals {
+                let new_person: Person = self.gen_person_arriving(floor_index, outcomes);
+                self.tot_arrived += 1_usize;
+                self.entrances[entrance_index].tot_arrived += 1_usize;
+
+                //If the person balks at the queue on their entrance floor, then count it
+                //and skip them
+                if new_person.is_balking(queue_lengths[floor_index]) {
+                    self.tot_balked += 1_usize;
+                    self.entrances[entrance_index].tot_balked += 1_usize;
+                    continue;
+                }
+
+                //Otherwise the person joins the queue if they are waiting
+                if new_person.is_waiting() {
+                    queue_lengths[floor_index] += 1_usize;
+                }
+                arrivals.push(new_person);
+            }
+        }
+
+        //Extend the entrance floors with the new arrivals, handling anyone who does not
+        //fit according to the overflow policy
+        let people_rejected: Vec<Person> = self.try_extend_entrance_floors(arrivals);
+        match self.overflow_policy {
+            OverflowPolicy::Queue => {
+                self.outside_queue.extend(people_rejected);
+                Vec::new()
+            },
+            OverflowPolicy::Drop | OverflowPolicy::Block => {
+                self.tot_rejected += people_rejected.len();
+                for pers in people_rejected.iter() {
+                    if let Some(entrance_index) = self.get_entrance_index(pers.floor_on) {
+                        self.entrances[entrance_index].tot_rejected += 1_usize;
+                    }
+                }
+                people_rejected
+            }
+        }
+    }
+
+    /// Generate a single person arriving at the building on the given entrance floor,
+    /// randomizing their mass and whether they bring along cargo according to the
+    /// building's arrival load, and their exit floor if the building has several
+    /// entrances.  The outcomes are decided by the given `OutcomeSource`.
+    pub fn gen_person_arriving(&self, floor_on: usize, outcomes: &mut dyn OutcomeSource) -> Person {
+        //Randomize the person's mass, ensuring it is positive
+        let mut mass: f64 = match &self.dst_mass {
+            Some(dst_mass) => outcomes.gen_value(ValueOutcome::Mass, &mut |rng| dst_mass.sample(rng).max(1.0_f64)),
+            None => self.mass_mean
+        };
+
+        //Randomize whether the person brings along cargo
+        let mut volume: f64 = 0.0_f64;
+        if self.p_cargo > 0.0_f64 && outcomes.gen_bool(BoolOutcome::Cargo, &mut |rng| self.dst_cargo.sample(rng)) {
+            mass += self.cargo_mass;
+            volume = self.cargo_volume;
+        }
+
+        //Initialize the person with a random destination floor, and their abandonment
+        //behavior
+        let num_floors: usize = self.floors.len();
+        let floor_to: usize = outcomes.gen_count(CountOutcome::Destination, &mut |rng| Person::gen_floor_to(num_floors, rng));
+        let mut new_person: Person = Person::from_trip(P_OUT, P_TIP, floor_to, mass, volume);
+        new_person.balk_threshold = self.balk_threshold;
+        new_person.patience = self.dst_patience.as_ref().map(|dst_patience| {
+            outcomes.gen_count(CountOutcome::Patience, &mut |rng| dst_patience.sample(rng).ceil() as usize)
+        });
+        new_person.max_stairs = self.max_stairs;
+
+        //Place the person on their entrance floor, and pick their exit floor
+        new_person.floor_on = floor_on;
+        new_person.exit_floor = self.entrances[self.gen_entrance_index(CountOutcome::ExitEntrance, outcomes)].floor_index;
+
+        //Return the person
+        new_person
+    }
+
+    /// Remove the people throughout the building's floors who give up on the elevator.
+    /// People who renege leave the building, and are counted as reneged.  People whose
+    /// trip is short enough take the stairs to their desired floor instead, and are
+    /// counted as stair trips.  If a desired floor is at capacity, then the person is
+    /// handled according to the overflow policy: either rejected, or kept waiting for
+    /// the elevator on their current floor, no longer willing to take the stairs.
+    pub fn update_people_abandoning(&mut self) {
+        //Loop through the floors and collect the people abandoning the elevator
+        for floor_index in 0..self.floors.len() {
+            let people_abandoning: Vec<Person> = self.floors[floor_index].flush_people_abandoning();
+
+            //Loop through the people abandoning
+            for mut pers in people_abandoning.into_iter() {
+                //If the person is not taking the stairs, then they renege and leave the
+                //building
+                if !pers.is_taking_stairs() {
+                    self.tot_reneged += 1_usize;
+                    continue;
+                }
+
+                //Otherwise walk the person to their desired floor, and if they fit on
+                //it, then count the trip
+                pers.floor_on = pers.floor_to;
+                let people_rejected: Vec<Person> = self.floors[pers.floor_to].try_extend(vec![pers]);
+                if people_rejected.is_empty() {
+                    self.tot_stairs += 1_usize;
+                    continue;
+                }
+
+                //Otherwise handle the person according to the overflow policy
+                match self.overflow_policy {
+                    OverflowPolicy::Drop => {
+                        self.tot_rejected += people_rejected.len();
+                    },
+                    OverflowPolicy::Queue | OverflowPolicy::Block => {
+                        for mut pers_rejected in people_rejected.into_iter() {
+                            pers_rejected.floor_on = floor_index;
+                            pers_rejected.max_stairs = 0_usize;
+                            let people_dropped: Vec<Person> = self.floors[floor_index].try_extend(vec![pers_rejected]);
+                            self.tot_rejected += people_dropped.len();
+                        }
+                    }
+                }
+            }
+        }
     }
 
     /// Given the number of people who decided to tip, generate the total value of their tips
-    pub fn gen_tip_value(&self, num_tips: usize, rng: &mut impl Rng) -> f64 {
+    pub fn gen_tip_value(&self, num_tips: usize, rng: &mut dyn RngCore) -> f64 {
         //Initialize a float to store the tip value
         let mut tip_value: f64 = 0.0_f64;
 
@@ -185,14 +1176,27 @@ impl Building {
     /// the floor is waiting for the elevator.
     pub fn exchange_people_on_elevator(&mut self) {
         for elevator in self.elevators.iter_mut() {
-            //If the elevator is not stopped then continue
-            if !elevator.stopped {
+            //Reset the elevator's exchange counts
+            elevator.num_boarded = 0_usize;
+            elevator.num_alighted = 0_usize;
+
+            //If the elevator is not stopped or is out of service then continue
+            if !elevator.stopped || elevator.is_out_of_service() {
                 continue;
             }
 
             //Get the elevator's floor index
             let floor_index: usize = elevator.floor_on;
 
+            //If the elevator is asleep and people are waiting on its floor, then
+            //wake it up, and if it is still waking up then continue
+            if elevator.sleeping && self.floors[floor_index].are_people_waiting() {
+                elevator.wake();
+            }
+            if elevator.sleeping || elevator.is_waking() {
+                continue;
+            }
+
             //Get the floor's free capacity and the floor's waiting capacity
             let floor_free_capacity: usize = self.floors[floor_index].get_free_capacity();
             let floor_wait_capacity: usize = self.floors[floor_index].get_num_people_waiting();
@@ -210,9 +1214,58 @@ impl Building {
                 floor_exchange_capacity
             };
 
-            //Move people off the floor and off the elevator
-            let people_leaving_floor: Vec<Person> = self.floors[floor_index].flush_people_entering_elevator(exchange_capacity);
+            //Move people off the elevator who are going to this floor, and split off
+            //those who do not yet fit on the floor
             let mut people_leaving_elevator: Vec<Person> = elevator.flush_people_leaving_elevator(exchange_capacity);
+            let num_alighting: usize = people_leaving_elevator.len().min(self.floors[floor_index].get_free_capacity());
+            let mut people_not_alighting: Vec<Person> = people_leaving_elevator.split_off(num_alighting);
+
+            //Move people off the floor one at a time while they fit within the elevator's
+            //remaining capacity, load, and volume.  Each person boarding frees a spot on
+            //the floor for one of the people not yet alighting, and the rest of them are
+            //treated as still on the elevator.  No one boards during a fire-service recall
+            //since hall calls are cancelled.
+            let boarding_capacity: usize = match self.mode {
+                BuildingMode::FireRecall { .. } => 0_usize,
+                _ => exchange_capacity
+            };
+            let mut num_boarding: usize = 0_usize;
+            while num_boarding < boarding_capacity {
+                //Calculate the elevator's free capacity, load, and volume
+                let people_staying: &[Person] = if people_not_alighting.is_empty() {
+                    &people_not_alighting[..]
+                } else {
+                    &people_not_alighting[1..]
+                };
+                let free_capacity: usize = elevator.get_free_capacity().saturating_sub(people_staying.len());
+                let free_load: f64 = elevator.get_free_load() - people_staying.iter().map(|pers| pers.mass).sum::<f64>();
+                let free_volume: f64 = elevator.get_free_volume() - people_staying.iter().map(|pers| pers.volume).sum::<f64>();
+
+                //Move the next person who fits off the floor, if any
+                let people_boarding: Vec<Person> = self.floors[floor_index].flush_people_entering_elevator(
+                    free_capacity.min(1_usize), free_load, free_volume
+                );
+                if people_boarding.is_empty() {
+                    break;
+                }
+                elevator.people.extend(people_boarding);
+                num_boarding += 1_usize;
+
+                //Let one of the people not yet alighting take the freed spot on the floor
+                if !people_not_alighting.is_empty() {
+                    people_leaving_elevator.push(people_not_alighting.remove(0));
+                }
+            }
+
+            //Handle the people who could not get off according to the overflow policy
+            match self.overflow_policy {
+                OverflowPolicy::Drop => {
+                    self.tot_rejected += people_not_alighting.len();
+                },
+                OverflowPolicy::Queue | OverflowPolicy::Block => {
+                    elevator.people.extend(people_not_alighting);
+                }
+            }
 
             //Aggregate the wait times of the people leaving the elevator into the average and reset
             let wait_times: usize = people_leaving_elevator.get_aggregate_wait_time();
@@ -229,17 +1282,34 @@ impl Building {
             self.wait_time_denom += num_people;
             people_leaving_elevator.reset_wait_times();
 
-            //Extend the current floor and elevator with the people getting on and off
-            elevator.extend(people_leaving_floor);
-            self.floors[floor_index].extend(people_leaving_elevator);
+            //Count the people exchanged, then extend the current floor with the people
+            //getting off, who fit since the exchange is limited to the floor's free
+            //capacity, and count anyone who does not as rejected
+            elevator.num_boarded = num_boarding;
+            elevator.num_alighted = people_leaving_elevator.len();
+            let people_rejected: Vec<Person> = self.floors[floor_index].try_extend(people_leaving_elevator);
+            self.tot_rejected += people_rejected.len();
         }
     }
 
-    /// Removes anyone who is leaving the first floor, and generates tips
-    pub fn flush_and_update_tips(&mut self, rng: &mut impl Rng) {
-        let people_leaving_floor: Vec<Person> = self.floors.flush_first_floor();
-        let num_tips: usize = people_leaving_floor.gen_num_tips(rng);
-        let tip_value: f64 = self.gen_tip_value(num_tips, rng);
+    /// Removes anyone who is leaving the building from its entrance floors, counting them
+    /// as departed through their entrance, and generates their tips.  The tips are
+    /// decided by the given `OutcomeSource`.
+    pub fn flush_and_update_tips(&mut self, outcomes: &mut dyn OutcomeSource) {
+        let people_leaving_floor: Vec<Person> = self.floors.flush_exit_floors(&self.get_entrance_floors());
+        self.tot_departed += people_leaving_floor.len();
+        for pers in people_leaving_floor.iter() {
+            if let Some(entrance_index) = self.get_entrance_index(pers.floor_on) {
+                self.entrances[entrance_index].tot_departed += 1_usize;
+            }
+        }
+        if people_leaving_floor.is_empty() {
+            return;
+        }
+        let tip_value: f64 = outcomes.gen_value(ValueOutcome::Tips, &mut |rng| {
+            let num_tips: usize = people_leaving_floor.gen_num_tips(rng);
+            self.gen_tip_value(num_tips, rng)
+        });
         self.tot_tips += tip_value;
     }
 
@@ -309,7 +1379,23 @@ impl std::fmt::Display for Building {
         let wait_time_str: String = format!("Average wait time:\t{:.2}", self.avg_wait_time);
         let energy_str: String = format!("Average energy spent:\t{:.2}", self.avg_energy);
         let tip_str: String = format!("Total tips collected:\t${:.2}", self.tot_tips);
-        building_status = [building_status, wait_time_str, energy_str, tip_str].join("\n");
+        let abandoned_str: String = format!("Abandoned trips:\t{} ({} balked, {} reneged)", self.get_num_abandoned(), self.tot_balked, self.tot_reneged);
+        building_status = [building_status, wait_time_str, energy_str, tip_str, abandoned_str].join("\n");
+
+        //If the building has several entrances, then add the metrics of each entrance
+        if self.entrances.len() > 1_usize {
+            for entrance in self.entrances.iter() {
+                let entrance_str: String = format!(
+                    "Entrance {}:\t\t{} arrived, {} departed, {} balked, {} rejected",
+                    self.floors[entrance.floor_index].name,
+                    entrance.tot_arrived,
+                    entrance.tot_departed,
+                    entrance.tot_balked,
+                    entrance.tot_rejected
+                );
+                building_status = [building_status, entrance_str].join("\n");
+            }
+        }
 
         //Format the string and return
         f.write_str(&building_status)
@@ -340,13 +1426,13 @@ impl Floors for Building {
     /// Randomly generates the people leaving each floor using each `Floor`'s
     /// `gen_people_leaving` function, which itself uses each `Person`'s `gen_is_leaving`
     /// function.
-    fn gen_people_leaving(&mut self, rng: &mut impl Rng) {
-        self.floors.gen_people_leaving(rng)
+    fn gen_people_leaving(&mut self, outcomes: &mut dyn OutcomeSource) {
+        self.floors.gen_people_leaving(outcomes)
     }
 
-    /// Removes anyone who is leaving the first floor, and generates tips
-    fn flush_first_floor(&mut self) -> Vec<Person> {
-        self.floors.flush_first_floor()
+    /// Removes anyone who is leaving the building from the given exit floors
+    fn flush_exit_floors(&mut self, exit_floors: &[usize]) -> Vec<Person> {
+        self.floors.flush_exit_floors(exit_floors)
     }
 
     /// Increments the waiting times among people who are waiting/not at their destination
@@ -365,4 +1451,83 @@ impl Floors for Building {
     fn update_capacities(&mut self, capacity: usize) {
         self.floors.update_capacities(capacity);
     }
+
+    /// Gets the number of people on each floor.
+    fn get_num_people_per_floor(&self) -> Vec<usize> {
+        self.floors.get_num_people_per_floor()
+    }
+
+    /// Evacuates the people throughout the building's floors and elevators by
+    /// directing them to the given exit floor and marking them as leaving.
+    fn evacuate(&mut self, floor_to: usize) {
+        self.elevators.evacuate(floor_to);
+        self.floors.evacuate(floor_to);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use super::*;
+    use crate::controller::NearestController;
+    use crate::simulator::Simulator;
+
+    //Run a building with small floors and heavy arrivals under the given overflow
+    //policy, so the lobby fills up, checking that people are conserved every step
+    fn run_full_lobby(overflow_policy: OverflowPolicy) -> Building {
+        let mut building: Building = Building::from(
+            4_usize,
+            1_usize,
+            8.0_f64,
+            5_usize,
+            4_usize,
+            5.0_f64,
+            2.5_f64,
+            0.5_f64
+        );
+        building.overflow_policy = overflow_policy;
+        building.debug = true;
+        let mut simulator: Simulator = Simulator::from(building, Box::new(NearestController::new()));
+        let mut rng: StdRng = StdRng::seed_from_u64(0_u64);
+        let mut lobby_filled: bool = false;
+        for _ in 0_usize..200_usize {
+            simulator.step(&mut rng).unwrap();
+            let building: &Building = &simulator.building;
+            let num_on_floors: usize = building.floors.iter().map(|floor| floor.get_num_people()).sum();
+            let num_on_elevators: usize = building.elevators.iter().map(|elevator| elevator.get_num_people()).sum();
+            assert_eq!(
+                num_on_floors + num_on_elevators + building.outside_queue.len() + building.tot_departed +
+                    building.tot_rejected + building.tot_balked + building.tot_reneged,
+                building.tot_arrived
+            );
+            assert!(building.is_conserved());
+            assert!(building.floors.iter().all(|floor| floor.get_num_people() <= floor.capacity));
+            lobby_filled = lobby_filled || simulator.building.floors[0].get_free_capacity() == 0_usize;
+        }
+        assert!(lobby_filled);
+        simulator.building
+    }
+
+    #[test]
+    fn drop_policy_rejects_overflow() {
+        let building: Building = run_full_lobby(OverflowPolicy::Drop);
+        assert!(building.tot_rejected > 0_usize);
+        assert!(building.outside_queue.is_empty());
+    }
+
+    #[test]
+    fn queue_policy_queues_overflow() {
+        let building: Building = run_full_lobby(OverflowPolicy::Queue);
+        assert_eq!(building.tot_rejected, 0_usize);
+        assert!(!building.outside_queue.is_empty());
+    }
+
+    #[test]
+    fn block_policy_rejects_arrivals_only() {
+        let building: Building = run_full_lobby(OverflowPolicy::Block);
+        assert!(building.tot_rejected > 0_usize);
+        assert!(building.outside_queue.is_empty());
+        assert_eq!(building.tot_rejected, building.entrances[0].tot_rejected);
+    }
 }
\ No newline at end of file
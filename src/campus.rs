@@ -0,0 +1,304 @@
+//Import standard/imported modules
+use std::fmt;
+use rand::RngCore;
+use rand::distributions::{Distribution, WeightedIndex};
+use statrs::distribution::Poisson;
+
+//Import source modules
+use crate::building::Building;
+use crate::simulator::Simulator;
+use crate::emergency::BuildingMode;
+use crate::validation::InvariantViolation;
+
+/// # `ArrivalSplit` trait
+///
+/// An `ArrivalSplit` implementation decides how a `Campus` splits its arrivals across
+/// its buildings.  Each time step, every arrival goes to a building picked at random
+/// in proportion to the building's weight.
+pub trait ArrivalSplit {
+    /// Expected to return the weight of each of the campus's buildings during the given
+    /// time step.  Weights must not be negative, and missing weights are treated as
+    /// zero.
+    fn get_weights(&self, time_step: usize, num_buildings: usize) -> Vec<f64>;
+}
+
+/// # `WeightedSplit` struct
+///
+/// A `WeightedSplit` implements the `ArrivalSplit` trait.  It splits arrivals across
+/// the buildings by fixed weights.
+pub struct WeightedSplit {
+    pub weights: Vec<f64>
+}
+
+//Implement the WeightedSplit interface
+impl WeightedSplit {
+    /// Initialize a new WeightedSplit given the weight of each building
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let my_split: WeightedSplit = WeightedSplit::from(vec![2.0_f64, 1.0_f64]);
+    /// ```
+    pub fn from(weights: Vec<f64>) -> WeightedSplit {
+        WeightedSplit {
+            weights: weights
+        }
+    }
+}
+
+//Implement the ArrivalSplit trait for the WeightedSplit
+impl ArrivalSplit for WeightedSplit {
+    /// Return the fixed weights, regardless of the time step
+    fn get_weights(&self, _time_step: usize, _num_buildings: usize) -> Vec<f64> {
+        self.weights.clone()
+    }
+}
+
+/// # `ScheduledSplit` struct
+///
+/// A `ScheduledSplit` implements the `ArrivalSplit` trait.  It splits arrivals across
+/// the buildings by weights which vary over a repeating period, such as a day, to
+/// model preferences which change with the time of day.  The schedule holds the time
+/// step within the period at which each set of weights takes effect.  Before the
+/// first entry of the period, the last entry of the previous period stays in effect.
+pub struct ScheduledSplit {
+    pub period: usize,
+    pub schedule: Vec<(usize, Vec<f64>)>
+}
+
+//Implement the ScheduledSplit interface
+impl ScheduledSplit {
+    /// Initialize a new ScheduledSplit given the length of the period in time steps,
+    /// and the schedule of weights as pairs of the time step within the period at
+    /// which the weights take effect and the weight of each building.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// //Mornings favor the first tower, afternoons favor the second
+    /// let my_split: ScheduledSplit = ScheduledSplit::from(1000_usize, vec![
+    ///     (0_usize, vec![3.0_f64, 1.0_f64]),
+    ///     (500_usize, vec![1.0_f64, 3.0_f64])
+    /// ]);
+    /// ```
+    pub fn from(period: usize, mut schedule: Vec<(usize, Vec<f64>)>) -> ScheduledSplit {
+        schedule.sort_by_key(|(start_step, _)| *start_step);
+        ScheduledSplit {
+            period: period.max(1_usize),
+            schedule: schedule
+        }
+    }
+}
+
+//Implement the ArrivalSplit trait for the ScheduledSplit
+impl ArrivalSplit for ScheduledSplit {
+    /// Return the weights in effect at the given time step within the period
+    fn get_weights(&self, time_step: usize, _num_buildings: usize) -> Vec<f64> {
+        let period_step: usize = time_step % self.period;
+        self.schedule.iter()
+            .rev()
+            .find(|(start_step, _)| *start_step <= period_step)
+            .or(self.schedule.last())
+            .map(|(_, weights)| weights.clone())
+            .unwrap_or_default()
+    }
+}
+
+/// # `Campus` struct
+///
+/// A `Campus` owns several buildings, each run by its own `Simulator` under its own
+/// `ElevatorController`, which share a single arrival process, such as towers fed by
+/// a shared parking garage.  Each time step the campus samples its arrivals from a
+/// Poisson distribution, splits them across the buildings according to its
+/// `ArrivalSplit`, then steps each building's simulator in order.  Every building
+/// steps under the campus's clock using the same rng, so a campus run is repeatable
+/// from a single seed.  Buildings in an emergency mode receive no arrivals, and if
+/// every building is in an emergency mode then no one arrives.
+pub struct Campus {
+    pub simulators: Vec<Simulator>,
+    pub split: Box<dyn ArrivalSplit>,
+    pub time_step: usize,
+    pub tot_arrived: usize,
+    p_in: f64,
+    dst_in: Option<Poisson>
+}
+
+//Implement the Campus interface
+impl Campus {
+    /// Initialize a new, empty Campus given the expected number of arrivals per time
+    /// step across the campus, and how the arrivals are split across its buildings.  A
+    /// campus expecting no arrivals, or an invalid number of them, never generates
+    /// any.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let mut my_campus: Campus = Campus::from(1.0_f64, Box::new(WeightedSplit::from(vec![2.0_f64, 1.0_f64])));
+    /// my_campus.add_building(Simulator::from(
+    ///     Building::from(10_usize, 3_usize, 0.5_f64, 100_usize, 10_usize, 5.0_f64, 2.5_f64, 0.5_f64),
+    ///     Box::new(NearestController::new())
+    /// ));
+    /// my_campus.add_building(Simulator::from(
+    ///     Building::from(6_usize, 2_usize, 0.5_f64, 100_usize, 10_usize, 5.0_f64, 2.5_f64, 0.5_f64),
+    ///     Box::new(NearestController::new())
+    /// ));
+    /// ```
+    pub fn from(p_in: f64, split: Box<dyn ArrivalSplit>) -> Campus {
+        Campus {
+            simulators: Vec::new(),
+            split: split,
+            time_step: 0_usize,
+            tot_arrived: 0_usize,
+            p_in: p_in,
+            dst_in: if p_in > 0.0_f64 { Poisson::new(p_in).ok() } else { None }
+        }
+    }
+
+    /// Add a building to the campus given the simulator which owns it.  The
+    /// building's own arrival rate is ignored, since its arrivals come from the
+    /// campus.
+    pub fn add_building(&mut self, simulator: Simulator) {
+        self.simulators.push(simulator);
+    }
+
+    /// Get the number of buildings on the campus
+    pub fn get_num_buildings(&self) -> usize {
+        self.simulators.len()
+    }
+
+    /// Get the expected number of arrivals per time step across the campus
+    pub fn get_p_in(&self) -> f64 {
+        self.p_in
+    }
+
+    /// Immutably borrow the building at the given index
+    pub fn get_building(&self, building_index: usize) -> &Building {
+        &self.simulators[building_index].building
+    }
+
+    /// Generate the number of people arriving at each building during the current time
+    /// step, by sampling the campus's arrivals and assigning each to a building picked
+    /// according to the split's weights
+    pub fn gen_arrivals_per_building(&mut self, mut rng: &mut dyn RngCore) -> Vec<usize> {
+        //Get the weights, zeroing those of buildings in an emergency mode
+        let num_buildings: usize = self.get_num_buildings();
+        let mut weights: Vec<f64> = self.split.get_weights(self.time_step, num_buildings);
+        weights.resize(num_buildings, 0.0_f64);
+        for (weight, simulator) in weights.iter_mut().zip(self.simulators.iter()) {
+            if simulator.building.mode != BuildingMode::Normal || !weight.is_finite() {
+                *weight = 0.0_f64;
+            }
+        }
+
+        //Sample the campus's arrivals, and assign each to a building
+        let num_arrivals: usize = match &self.dst_in {
+            Some(dst_in) => dst_in.sample(&mut rng) as usize,
+            None => 0_usize
+        };
+        let mut arrivals: Vec<usize> = vec![0_usize; num_buildings];
+        if let Ok(dst_building) = WeightedIndex::new(&weights) {
+            for _ in 0_usize..num_arrivals {
+                arrivals[dst_building.sample(&mut rng)] += 1_usize;
+            }
+            self.tot_arrived += num_arrivals;
+        }
+        arrivals
+    }
+
+    /// Run a single time step of the campus: split the arrivals across the buildings,
+    /// then step each building's simulator.  Every building is stepped even if one of
+    /// them finds an invariant violation, so that the buildings stay in sync, and the
+    /// first violation found is returned.
+    pub fn step(&mut self, rng: &mut dyn RngCore) -> Result<(), InvariantViolation> {
+        //Split the arrivals across the buildings
+        let arrivals: Vec<usize> = self.gen_arrivals_per_building(rng);
+
+        //Step each building with its share of the arrivals
+        let mut result: Result<(), InvariantViolation> = Ok(());
+        for (simulator, num_arrivals) in self.simulators.iter_mut().zip(arrivals) {
+            let building_result: Result<(), InvariantViolation> = simulator.step_with_arrivals(Some(num_arrivals), rng);
+            if result.is_ok() {
+                result = building_result;
+            }
+        }
+
+        //Increment the time step
+        self.time_step += 1_usize;
+        result
+    }
+
+    /// Get the average wait time across the campus, weighing each building's average
+    /// wait time by the number of wait times averaged into it
+    pub fn get_avg_wait_time(&self) -> f64 {
+        let mut tot_wait_time: f64 = 0.0_f64;
+        let mut num_wait_times: usize = 0_usize;
+        for simulator in self.simulators.iter() {
+            let building: &Building = &simulator.building;
+            tot_wait_time += building.avg_wait_time * building.get_num_wait_times() as f64;
+            num_wait_times += building.get_num_wait_times();
+        }
+        if num_wait_times == 0_usize {
+            0.0_f64
+        } else {
+            tot_wait_time / num_wait_times as f64
+        }
+    }
+
+    /// Get the average energy spent per time step across the campus, which is the sum
+    /// of the buildings' average energies
+    pub fn get_avg_energy(&self) -> f64 {
+        self.simulators.iter().map(|simulator| simulator.building.avg_energy).sum()
+    }
+
+    /// Get the total tips collected across the campus
+    pub fn get_tot_tips(&self) -> f64 {
+        self.simulators.iter().map(|simulator| simulator.building.tot_tips).sum()
+    }
+
+    /// Get the total number of people who departed across the campus
+    pub fn get_tot_departed(&self) -> usize {
+        self.simulators.iter().map(|simulator| simulator.building.tot_departed).sum()
+    }
+
+    /// Get the total number of people rejected across the campus
+    pub fn get_tot_rejected(&self) -> usize {
+        self.simulators.iter().map(|simulator| simulator.building.tot_rejected).sum()
+    }
+
+    /// Get the total number of trips abandoned across the campus
+    pub fn get_num_abandoned(&self) -> usize {
+        self.simulators.iter().map(|simulator| simulator.building.get_num_abandoned()).sum()
+    }
+
+    /// Get the total number of people in the campus's buildings
+    pub fn get_num_people(&self) -> usize {
+        self.simulators.iter().map(|simulator| simulator.building.get_num_people_in_building()).sum()
+    }
+}
+
+//Implement the Display trait for the Campus
+impl fmt::Display for Campus {
+    /// Format the campus as a line of metrics per building followed by the metrics
+    /// aggregated across the campus
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Building\tArrived\tDeparted\tWait time\tEnergy\tTips")?;
+        for (i, simulator) in self.simulators.iter().enumerate() {
+            let building: &Building = &simulator.building;
+            writeln!(
+                f,
+                "{}\t\t{}\t{}\t\t{:.2}\t\t{:.2}\t${:.2}",
+                i,
+                building.tot_arrived,
+                building.tot_departed,
+                building.avg_wait_time,
+                building.avg_energy,
+                building.tot_tips
+            )?;
+        }
+        writeln!(f, "Campus arrivals:\t{}", self.tot_arrived)?;
+        writeln!(f, "Average wait time:\t{:.2}", self.get_avg_wait_time())?;
+        writeln!(f, "Average energy spent:\t{:.2}", self.get_avg_energy())?;
+        writeln!(f, "Total tips collected:\t${:.2}", self.get_tot_tips())?;
+        write!(f, "Abandoned trips:\t{}", self.get_num_abandoned())
+    }
+}
\ No newline at end of file
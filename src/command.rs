@@ -0,0 +1,50 @@
+//Import standard/imported modules
+use serde::{Deserialize, Serialize};
+
+/// # `ElevatorCommand` enum
+///
+/// An `ElevatorCommand` is an instruction for a single elevator, returned by an
+/// `ElevatorController` after observing a `BuildingView`.  The `Building` validates
+/// each command and applies the valid ones, so that controllers decide the policy
+/// while the building remains in charge of the mechanics.  Each command moves the
+/// elevator at most one floor per time step.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ElevatorCommand {
+    /// Move the elevator toward the given floor, stopping once it is reached
+    GoToFloor { elevator_index: usize, floor_index: usize },
+    /// Hold the elevator stopped on its current floor.  A sleeping elevator stays
+    /// asleep.
+    Stop { elevator_index: usize },
+    /// Hold the elevator stopped on its current floor with its doors open, waking it
+    /// if it is asleep so that people can be exchanged
+    OpenDoors { elevator_index: usize },
+    /// Move the idle elevator toward the given parking floor, and put it to sleep
+    /// once it has been idle for `sleep_after` time steps, with the given wake-up
+    /// delay.  If `sleep_after` is `None` then the elevator never sleeps.
+    Park { elevator_index: usize, floor_index: usize, sleep_after: Option<usize>, wake_delay: usize }
+}
+
+/// # `ElevatorCommand` type implementation
+///
+/// The following functions are used by the `Building` type to validate and apply
+/// elevator commands.
+impl ElevatorCommand {
+    /// Get the index of the elevator the command is for
+    pub fn get_elevator_index(&self) -> usize {
+        match self {
+            ElevatorCommand::GoToFloor { elevator_index, .. } |
+            ElevatorCommand::Stop { elevator_index } |
+            ElevatorCommand::OpenDoors { elevator_index } |
+            ElevatorCommand::Park { elevator_index, .. } => *elevator_index
+        }
+    }
+
+    /// Get the index of the floor the command sends the elevator to, if any
+    pub fn get_floor_index(&self) -> Option<usize> {
+        match self {
+            ElevatorCommand::GoToFloor { floor_index, .. } |
+            ElevatorCommand::Park { floor_index, .. } => Some(*floor_index),
+            ElevatorCommand::Stop { .. } | ElevatorCommand::OpenDoors { .. } => None
+        }
+    }
+}
\ No newline at end of file
@@ -1,28 +1,65 @@
 //Import source modules
 use crate::building::Building;
-use crate::floors::Floors;
-use crate::people::People;
+use crate::parking::ParkingPolicy;
+use crate::view::BuildingView;
+use crate::command::ElevatorCommand;
 
 //Implement standard/imported modules
 use rand::{Rng, SeedableRng};
 use rand::rngs::StdRng;
 use rand::distributions::{Distribution, Uniform, Bernoulli};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+//Constants defining the discretized state space of the QLearningController: the
+//direction of an elevator (stopped, up, down), its load bucket (empty, partial,
+//full), and its pending calls (car and hall calls above and below it)
+const Q_NUM_DIRECTIONS: usize = 3_usize;
+const Q_NUM_LOAD_BUCKETS: usize = 3_usize;
+const Q_NUM_CALL_STATES: usize = 16_usize;
+
+//Constant defining the number of actions of the QLearningController: open doors,
+//go up, and go down
+const Q_NUM_ACTIONS: usize = 3_usize;
 
 /// # `ElevatorController` trait
 ///
-/// An `ElevatorController` implementation controls the elevators of a building.
+/// An `ElevatorController` implementation controls the elevators of a building.  Each
+/// time step it observes an immutable `BuildingView` and decides a set of
+/// `ElevatorCommand`s, which the `Simulator` owning the building validates and
+/// applies.  Controllers never hold the building itself.  The trait is object safe, so
+/// controllers can be picked at runtime as a `Box<dyn ElevatorController>`, for
+/// instance by name from a `ControllerRegistry`.
 pub trait ElevatorController {
-    fn get_building(&mut self) -> &Building;
-
-    fn get_building_mut(&mut self) -> &mut Building;
-
-    fn clone_building(&mut self) -> Building;
-
     fn can_be_upgraded(&self) -> bool;
 
     fn upgrade(&mut self, incrementation: f64);
 
-    fn update_elevators(&mut self);
+    fn decide(&mut self, view: &BuildingView) -> Vec<ElevatorCommand>;
+}
+
+/// Generate the command sending the elevator at the given index from its current floor
+/// to its decided floor.  If the elevator stays on its current floor or heads to its
+/// parking floor, then it is parked according to the parking policy so that it may
+/// sleep once it is idle.
+fn gen_elevator_command(elevator_index: usize, floor_on: usize, floor_to: usize,
+    parking_floor: Option<usize>, parking_policy: &Option<Box<dyn ParkingPolicy>>) -> ElevatorCommand {
+    //If the elevator is not staying put or parking, then send it to its floor
+    if floor_to != floor_on && Some(floor_to) != parking_floor {
+        return ElevatorCommand::GoToFloor { elevator_index: elevator_index, floor_index: floor_to };
+    }
+
+    //Otherwise park the elevator according to the parking policy, if any
+    match parking_policy {
+        Some(parking_policy) => ElevatorCommand::Park {
+            elevator_index: elevator_index,
+            floor_index: floor_to,
+            sleep_after: parking_policy.get_sleep_after(),
+            wake_delay: parking_policy.get_wake_delay()
+        },
+        None => ElevatorCommand::GoToFloor { elevator_index: elevator_index, floor_index: floor_to }
+    }
 }
 
 /// # `RandomController` struct
@@ -31,21 +68,21 @@ pub trait ElevatorController {
 /// generates destination floors for each of a building's elevators once the elevator
 /// reaches its destination floor.
  pub struct RandomController {
-    pub building: Building,
     num_floors: usize,
     floors_to: Vec<Option<usize>>,
     dst_to: Uniform<usize>,
     p_rational: f64,
     dst_rational: Bernoulli,
     upgradable: bool,
-    rng: StdRng
+    rng: StdRng,
+    parking_policy: Option<Box<dyn ParkingPolicy>>
 }
 
 //Implement the RandomController interface
 impl RandomController {
-    /// Initialize a new RandomController given a `Building`, an `StdRng` (from
-    /// the rand library), and an `f64` representing the probability that the
-    /// RandomController behaves rationally.
+    /// Initialize a new RandomController given the `Building` it will control, an
+    /// `StdRng` (from the rand library), and an `f64` representing the probability
+    /// that the RandomController behaves rationally.
     ///
     /// ## Example
     ///
@@ -60,12 +97,12 @@ impl RandomController {
     ///     0.5_f64
     /// );
     /// let my_controller: RandomController = RandomController::from(
-    ///     my_building,
+    ///     &my_building,
     ///     my_rng,
     ///     0.5_f64
     /// );
     /// ```
-    pub fn from(building: Building, rng: StdRng, p_rational: f64) -> RandomController {
+    pub fn from(building: &Building, rng: StdRng, p_rational: f64) -> RandomController {
         //Get the number of floors and elevators in the building
         let num_floors: usize = building.floors.len();
         let num_elevators: usize = building.elevators.len();
@@ -84,14 +121,14 @@ impl RandomController {
 
         //Initialize the controller
         RandomController {
-            building: building,
             num_floors: num_floors,
             floors_to: floors_to,
             dst_to: dst_to,
             p_rational: p_rational,
             dst_rational: Bernoulli::new(p_rational).unwrap(),
             upgradable: true,
-            rng: rng
+            rng: rng,
+            parking_policy: None
         }
     }
 
@@ -109,9 +146,9 @@ impl RandomController {
     ///     2.5_f64,
     ///     0.5_f64
     /// );
-    /// let my_controller: RandomController = RandomController::from(my_building);
+    /// let my_controller: RandomController = RandomController::from_building(&my_building);
     /// ```
-    pub fn from_building(building: Building) -> RandomController {
+    pub fn from_building(building: &Building) -> RandomController {
         //Initialize default values for the additional properties for this controller
         let rng = StdRng::from_seed(rand::thread_rng().gen());
         let p_rational = 0.0_f64;
@@ -120,26 +157,33 @@ impl RandomController {
         RandomController::from(building, rng, p_rational)
     }
 
+    /// Set the parking policy used by the RandomController to decide where its
+    /// idle elevators park and when they sleep.
+    pub fn set_parking_policy(&mut self, parking_policy: Box<dyn ParkingPolicy>) {
+        self.parking_policy = Some(parking_policy);
+    }
+
     /// Set the destination floors of the elevators randomly according to
-    /// random or rational logic, depending on the p_rational
-    pub fn update_floors_to(&mut self) {
+    /// random or rational logic, depending on the p_rational, given a view of
+    /// the building
+    pub fn update_floors_to(&mut self, view: &BuildingView) {
         //If the number of elevators in the building is greater than the number
         //of destination floors in the controller, then add new destination
         //floors
-        while self.building.elevators.len() > self.floors_to.len() {
+        while view.get_num_elevators() > self.floors_to.len() {
             self.floors_to.push(None);
         }
 
         //If the numer of floors in the building is greater than the number of
         //floors tracked by the controller, then update the number of floors
         //tracked by the controller and re-instantiate the dest distribution
-        if self.building.floors.len() != self.num_floors {
-            self.num_floors = self.building.floors.len();
+        if view.get_num_floors() != self.num_floors {
+            self.num_floors = view.get_num_floors();
             self.dst_to = Uniform::new(0, self.num_floors);
         }
 
         //Loop through the elevators in the building
-        for (i, elevator) in self.building.elevators.iter().enumerate() {
+        for (i, elevator) in view.elevators.iter().enumerate() {
             //If the destination floor for the elevator is None, then update it
             match self.floors_to[i] {
                 Some(_) => {},
@@ -156,13 +200,21 @@ impl RandomController {
                             }
                 
                             //Find the nearest waiting floor among people throughout the building
-                            let (nearest_wait_floor, min_wait_floor_dist): (usize, usize) = self.building.get_nearest_wait_floor(elevator.floor_on);
+                            let (nearest_wait_floor, min_wait_floor_dist): (usize, usize) = view.get_nearest_wait_floor(elevator.floor_on);
                 
                             //If the nearest wait floor is identified, then set as the dest floor
                             if min_wait_floor_dist != 0_usize {
                                 self.floors_to[i] = Some(nearest_wait_floor);
                                 continue;
                             }
+
+                            //If the elevator is idle, then send it to its parking floor
+                            if let Some(parking_policy) = &self.parking_policy {
+                                if let Some(parking_floor) = parking_policy.get_parking_floor(view, i) {
+                                    self.floors_to[i] = Some(parking_floor);
+                                    continue;
+                                }
+                            }
                         }
                     } else {
                         self.floors_to[i] = Some(self.dst_to.sample(&mut self.rng));
@@ -174,13 +226,12 @@ impl RandomController {
         }
     }
 
-    /// If any elevators are at their destination floor, then set that floor
-    /// to None so that it can be re-randomized next time step.
-    pub fn clear_floors_to(&mut self) {
+    /// If any elevators are at their destination floor in the given view of the
+    /// building, then set that floor to None so that it can be re-randomized.
+    pub fn clear_floors_to(&mut self, view: &BuildingView) {
         //Loop through the elevators in the building
-        for (i, elevator) in self.building.elevators.iter().enumerate() {
-            let dest_floor = self.floors_to[i].unwrap();
-            if dest_floor == elevator.floor_on {
+        for (i, elevator) in view.elevators.iter().enumerate() {
+            if self.floors_to.get(i).copied().flatten() == Some(elevator.floor_on) {
                 self.floors_to[i] = None;
             }
         }
@@ -189,22 +240,6 @@ impl RandomController {
 
 //Implement the ElevatorController trait for the RandomController
 impl ElevatorController for RandomController {
-    /// Immutably borrow the building belonging to the controller
-    fn get_building(&mut self) -> &Building {
-        &self.building
-    }
-
-    /// Mutably borrow the building belonging to the controller
-    fn get_building_mut(&mut self) -> &mut Building {
-        &mut self.building
-    }
-
-    /// Clone the building belonging to the controller.  Generally used when
-    /// swapping controllers.
-    fn clone_building(&mut self) -> Building {
-        self.building.clone()
-    }
-
     /// Return a boolean signifying whether the controller can be upgraded or
     /// not.
     fn can_be_upgraded(&self) -> bool {
@@ -232,28 +267,36 @@ impl ElevatorController for RandomController {
         self.dst_rational = Bernoulli::new(self.p_rational).unwrap();
     }
 
-    /// If the destination floor is None, then randomize a new destination floor.
-    /// If the elevator is not on its destination floor then move toward it.  If the
-    /// elevator is on its destination floor then stop it and set its destination
-    /// floor to None for randomization during the next step.
-    fn update_elevators(&mut self) {
-        //Update the destination floors
-        self.update_floors_to();
-        
-        //Loop through the dest floors and update the building's elevators accordingly
-        for (i, floor_to) in self.floors_to.iter().enumerate() {
-            //Unwrap the destination floor
-            let dest_floor: usize = floor_to.unwrap();
-
-            //Update the elevator's direction based on its destination floor
-            self.building.elevators[i].update_direction(dest_floor);
-
-            //Update the elevator
-            let _new_floor_index = self.building.elevators[i].update_floor();
+    /// Clear the destination floors of elevators which arrived at them during the
+    /// last time step, then randomize new destination floors where they are None.
+    /// Each available elevator is commanded to move toward its destination floor,
+    /// and parked according to the parking policy once it has nothing to do.
+    fn decide(&mut self, view: &BuildingView) -> Vec<ElevatorCommand> {
+        //Clear the destination floors of the elevators which arrived, then update them
+        self.clear_floors_to(view);
+        self.update_floors_to(view);
+
+        //Loop through the dest floors and command the building's elevators accordingly
+        let mut commands: Vec<ElevatorCommand> = Vec::new();
+        for (i, elevator) in view.elevators.iter().enumerate() {
+            //Skip elevators which are unavailable
+            if !elevator.is_available() {
+                continue;
+            }
+
+            //Unwrap the destination floor and get the elevator's parking floor
+            let dest_floor: usize = self.floors_to[i].unwrap();
+            let parking_floor: Option<usize> = match &self.parking_policy {
+                Some(parking_policy) => parking_policy.get_parking_floor(view, i),
+                None => None
+            };
+
+            //Command the elevator toward its destination floor
+            commands.push(gen_elevator_command(i, elevator.floor_on, dest_floor, parking_floor, &self.parking_policy));
         }
 
-        //Clear the destination floors if any elevators arrived at their destinations
-        self.clear_floors_to();
+        //Return the commands
+        commands
     }
 }
 
@@ -263,77 +306,44 @@ impl ElevatorController for RandomController {
 /// elevator's direction based on the nearest destination floor among people on the
 /// elevator, then the nearest floor with people waiting.
 pub struct NearestController {
-    pub building: Building,
-    upgradable: bool
+    upgradable: bool,
+    parking_policy: Option<Box<dyn ParkingPolicy>>
 }
 
 //Implement the NearestController interface
 impl NearestController {
-    /// Initialize a new NearestController given a `Building`.
+    /// Initialize a new NearestController without a parking policy.
     ///
     /// ## Example
     ///
     /// ```
-    /// let my_building: Building = Building::from(
-    ///     4_usize,
-    ///     2_usize,
-    ///     0.5_f64,
-    ///     5.0_f64,
-    ///     2.5_f64,
-    ///     0.5_f64
-    /// );
-    /// let my_controller: NearestController = NearestController::from(my_building);
+    /// let my_controller: NearestController = NearestController::new();
     /// ```
-    pub fn from(building: Building) -> NearestController {
+    pub fn new() -> NearestController {
         //Initialize the controller
         NearestController {
-            building: building,
-            upgradable: false
+            upgradable: false,
+            parking_policy: None
         }
     }
 
-    /// Initialize a new NearestController from just a building
-    ///
-    /// ## Example
-    ///
-    /// ```
-    /// let my_building: Building = Building::from(
-    ///     4_usize,
-    ///     2_usize,
-    ///     0.5_f64,
-    ///     5.0_f64,
-    ///     2.5_f64,
-    ///     0.5_f64
-    /// );
-    /// let my_controller: NearestController = NearestController::from(my_building);
-    /// ```
-    pub fn from_building(building: Building) -> NearestController {
-        //Initialize the controller
-        NearestController {
-            building: building,
-            upgradable: false
-        }
+    /// Set the parking policy used by the NearestController to decide where its
+    /// idle elevators park and when they sleep.
+    pub fn set_parking_policy(&mut self, parking_policy: Box<dyn ParkingPolicy>) {
+        self.parking_policy = Some(parking_policy);
     }
 }
 
-//Implement the ElevatorController trait for the NearestController
-impl ElevatorController for NearestController {
-    /// Get the building belonging to the controller
-    fn get_building(&mut self) -> &Building {
-        &self.building
-    }
-
-    /// Mutably borrow the building belonging to the controller
-    fn get_building_mut(&mut self) -> &mut Building {
-        &mut self.building
-    }
-
-    /// Clone the building belonging to the controller.  Generally used when
-    /// swapping controllers.
-    fn clone_building(&mut self) -> Building {
-        self.building.clone()
+//Implement the Default trait for the NearestController
+impl Default for NearestController {
+    /// Initialize a new NearestController without a parking policy
+    fn default() -> Self {
+        NearestController::new()
     }
+}
 
+//Implement the ElevatorController trait for the NearestController
+impl ElevatorController for NearestController {
     /// Return a boolean signifying whether the controller can be upgraded or
     /// not.  Always returns false, since the NearestController cannot be
     /// upgraded.
@@ -347,12 +357,12 @@ impl ElevatorController for NearestController {
 
     /// Decide each elevator's direction based on the nearest destination floor among
     /// people on the elevator, then the nearest floor with people waiting.
-    fn update_elevators(&mut self) {
+    fn decide(&mut self, view: &BuildingView) -> Vec<ElevatorCommand> {
         //Initialize a vector of decisions for the elevators
         let mut elevator_decisions: Vec<usize> = Vec::new();
 
         //Loop through the elevators in the building
-        for elevator in self.building.elevators.iter() {
+        for (i, elevator) in view.elevators.iter().enumerate() {
             //If stopped, check where to go next
             if elevator.stopped {
                 //Find the nearest destination floor among people on the elevator
@@ -365,13 +375,21 @@ impl ElevatorController for NearestController {
                 }
 
                 //Find the nearest waiting floor among people throughout the building
-                let (nearest_wait_floor, min_wait_floor_dist): (usize, usize) = self.building.get_nearest_wait_floor(elevator.floor_on);
+                let (nearest_wait_floor, min_wait_floor_dist): (usize, usize) = view.get_nearest_wait_floor(elevator.floor_on);
 
                 //If the nearest wait floor is identified, then update the elevator
                 if min_wait_floor_dist != 0_usize {
                     elevator_decisions.push(nearest_wait_floor);
                     continue;
                 }
+
+                //If the elevator is idle, then send it to its parking floor
+                if let Some(parking_policy) = &self.parking_policy {
+                    if let Some(parking_floor) = parking_policy.get_parking_floor(view, i) {
+                        elevator_decisions.push(parking_floor);
+                        continue;
+                    }
+                }
             } else {
                 //If moving down and on the bottom floor, then stop
                 if !elevator.moving_up && elevator.floor_on == 0_usize {
@@ -380,7 +398,7 @@ impl ElevatorController for NearestController {
                 }
 
                 //If moving up and on the top floor, then stop
-                if elevator.moving_up && elevator.floor_on == (self.building.floors.len() - 1_usize) {
+                if elevator.moving_up && elevator.floor_on == (view.get_num_floors() - 1_usize) {
                     elevator_decisions.push(elevator.floor_on);
                     continue;
                 }
@@ -392,7 +410,7 @@ impl ElevatorController for NearestController {
                 }
 
                 //If there are people waiting on the current floor, then stop
-                if self.building.are_people_waiting_on_floor(elevator.floor_on) {
+                if view.are_people_waiting_on_floor(elevator.floor_on) {
                     elevator_decisions.push(elevator.floor_on);
                     continue;
                 }
@@ -402,13 +420,377 @@ impl ElevatorController for NearestController {
             elevator_decisions.push(elevator.floor_on);
         }
 
-        //Loop through the elevator decisions and update the elevators
+        //Loop through the elevator decisions and command the available elevators
+        let mut commands: Vec<ElevatorCommand> = Vec::new();
         for (i, decision) in elevator_decisions.iter().enumerate() {
-            //Update the elevator direction
-            self.building.elevators[i].update_direction(*decision);
+            //Skip elevators which are unavailable
+            if !view.elevators[i].is_available() {
+                continue;
+            }
+
+            //Get the elevator's parking floor and command the elevator
+            let parking_floor: Option<usize> = match &self.parking_policy {
+                Some(parking_policy) => parking_policy.get_parking_floor(view, i),
+                None => None
+            };
+            commands.push(gen_elevator_command(i, view.elevators[i].floor_on, *decision, parking_floor, &self.parking_policy));
+        }
+
+        //Return the commands
+        commands
+    }
+}
+
+/// # `QLearningController` struct
+///
+/// A `QLearningController` implements the `ElevatorController` trait.  It learns online
+/// using tabular Q-learning, with one Q-table shared by all of the building's
+/// elevators.  Each elevator's state is discretized into its floor, its direction, its
+/// load bucket, and whether it has car calls and hall calls above and below it.  Each
+/// time step an elevator either opens its doors, goes up, or goes down, and the reward
+/// is the negative number of people waiting in the building.  Actions are explored
+/// with probability `epsilon`, which is decreased each time the controller is
+/// upgraded.
+pub struct QLearningController {
+    pub q_table: Vec<f64>,
+    pub alpha: f64,
+    pub gamma: f64,
+    pub epsilon: f64,
+    pub epsilon_min: f64,
+    pub learning: bool,
+    num_floors: usize,
+    prev_state_actions: Vec<Option<(usize, usize)>>,
+    rng: StdRng
+}
+
+//Implement the QLearningController interface
+impl QLearningController {
+    /// Initialize a new QLearningController given the `Building` it will control, an
+    /// `StdRng` (from the rand library), the learning rate, the discount factor, and
+    /// the initial and minimum exploration probabilities.  The Q-table is initialized
+    /// to zeros.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let my_rng = StdRng::seed_from_u64(0_u64);
+    /// let my_building: Building = Building::from(
+    ///     4_usize,
+    ///     2_usize,
+    ///     0.5_f64,
+    ///     5.0_f64,
+    ///     2.5_f64,
+    ///     0.5_f64
+    /// );
+    /// let my_controller: QLearningController = QLearningController::from(
+    ///     &my_building,
+    ///     my_rng,
+    ///     0.1_f64,
+    ///     0.95_f64,
+    ///     1.0_f64,
+    ///     0.05_f64
+    /// );
+    /// ```
+    pub fn from(building: &Building, rng: StdRng, alpha: f64, gamma: f64, epsilon: f64, epsilon_min: f64) -> QLearningController {
+        //Get the number of floors and elevators in the building
+        let num_floors: usize = building.floors.len();
+        let num_elevators: usize = building.elevators.len();
+
+        //Initialize the controller
+        QLearningController {
+            q_table: vec![0.0_f64; QLearningController::get_num_states_for(num_floors) * Q_NUM_ACTIONS],
+            alpha: alpha,
+            gamma: gamma,
+            epsilon: epsilon,
+            epsilon_min: epsilon_min,
+            learning: true,
+            num_floors: num_floors,
+            prev_state_actions: vec![None; num_elevators],
+            rng: rng
+        }
+    }
+
+    /// Get the number of discrete states for a building with the given number of
+    /// floors
+    fn get_num_states_for(num_floors: usize) -> usize {
+        num_floors.max(1_usize) * Q_NUM_DIRECTIONS * Q_NUM_LOAD_BUCKETS * Q_NUM_CALL_STATES
+    }
+
+    /// Get the number of discrete states in the controller's Q-table
+    pub fn get_num_states(&self) -> usize {
+        QLearningController::get_num_states_for(self.num_floors)
+    }
+
+    /// Discretize the state of the elevator at the given index in the given view of
+    /// the building, and return the index of the state in the Q-table.
+    pub fn get_state(&self, view: &BuildingView, elevator_index: usize) -> usize {
+        //Get the elevator's floor, clamped to the floors known to the Q-table
+        let elevator = &view.elevators[elevator_index];
+        let floor_on: usize = elevator.floor_on.min(self.num_floors.max(1_usize) - 1_usize);
+
+        //Get the elevator's direction
+        let direction: usize = if elevator.stopped {
+            0_usize
+        } else if elevator.moving_up {
+            1_usize
+        } else {
+            2_usize
+        };
+
+        //Get the elevator's load bucket
+        let load_bucket: usize = if elevator.num_people == 0_usize {
+            0_usize
+        } else if elevator.get_free_capacity() > 0_usize {
+            1_usize
+        } else {
+            2_usize
+        };
+
+        //Get the elevator's pending car calls and hall calls above and below it
+        let car_call_above: bool = elevator.car_calls.iter().any(|floor| *floor > elevator.floor_on);
+        let car_call_below: bool = elevator.car_calls.iter().any(|floor| *floor < elevator.floor_on);
+        let hall_call_above: bool = view.floors.iter().enumerate()
+            .any(|(i, floor)| i > elevator.floor_on && floor.are_people_waiting());
+        let hall_call_below: bool = view.floors.iter().enumerate()
+            .any(|(i, floor)| i < elevator.floor_on && floor.are_people_waiting());
+        let calls: usize = (car_call_above as usize) |
+            ((car_call_below as usize) << 1_usize) |
+            ((hall_call_above as usize) << 2_usize) |
+            ((hall_call_below as usize) << 3_usize);
+
+        //Combine the components into the index of the state
+        (((floor_on * Q_NUM_DIRECTIONS) + direction) * Q_NUM_LOAD_BUCKETS + load_bucket) * Q_NUM_CALL_STATES + calls
+    }
+
+    /// Get the actions available to the elevator at the given index in the given
+    /// view of the building.  Elevators cannot go up from the top floor or down from
+    /// the bottom floor.
+    fn get_valid_actions(&self, view: &BuildingView, elevator_index: usize) -> Vec<usize> {
+        let floor_on: usize = view.elevators[elevator_index].floor_on;
+        let mut valid_actions: Vec<usize> = vec![0_usize];
+        if floor_on + 1_usize < view.get_num_floors() {
+            valid_actions.push(1_usize);
+        }
+        if floor_on > 0_usize {
+            valid_actions.push(2_usize);
+        }
+        valid_actions
+    }
+
+    /// Get the action with the highest Q-value among the given actions in the given
+    /// state, along with its Q-value
+    fn get_best_action(&self, state: usize, actions: &[usize]) -> (usize, f64) {
+        let mut best_action: usize = actions[0];
+        let mut best_value: f64 = self.q_table[state * Q_NUM_ACTIONS + best_action];
+        for action in actions.iter().skip(1_usize) {
+            let value: f64 = self.q_table[state * Q_NUM_ACTIONS + action];
+            if value > best_value {
+                best_action = *action;
+                best_value = value;
+            }
+        }
+        (best_action, best_value)
+    }
+
+    /// Calculate the reward for the last time step given the current view of the
+    /// building, as the negative number of people waiting on the floors and the
+    /// elevators.
+    pub fn get_reward(&self, view: &BuildingView) -> f64 {
+        let num_waiting_floors: usize = view.floors.iter().map(|floor| floor.num_people_waiting).sum();
+        let num_waiting_elevators: usize = view.elevators.iter().map(|elevator| elevator.num_people).sum();
+        -((num_waiting_floors + num_waiting_elevators) as f64)
+    }
+
+    /// Save the Q-table to a text file at the given path.  The first line holds the
+    /// number of states and actions, and each following line holds the Q-values of
+    /// one state separated by spaces.
+    pub fn save_table(&self, path: &Path) -> io::Result<()> {
+        //Write the dimensions of the table
+        let mut table_str: String = format!("{} {}\n", self.get_num_states(), Q_NUM_ACTIONS);
+
+        //Write each state's Q-values
+        for state_values in self.q_table.chunks(Q_NUM_ACTIONS) {
+            let values_str: Vec<String> = state_values.iter().map(|value| value.to_string()).collect();
+            table_str.push_str(&values_str.join(" "));
+            table_str.push('\n');
+        }
+
+        //Write the table to the file
+        fs::write(path, table_str)
+    }
+
+    /// Load the Q-table from a text file at the given path, in the format written by
+    /// `save_table`.  Returns an error if the file cannot be read or parsed, or if the
+    /// table's dimensions do not match the controller's building.
+    pub fn load_table(&mut self, path: &Path) -> io::Result<()> {
+        //Read the file and split it into lines
+        let table_str: String = fs::read_to_string(path)?;
+        let mut lines = table_str.lines();
+        let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg.to_string());
+
+        //Parse and check the dimensions of the table
+        let dims: Vec<usize> = lines.next()
+            .ok_or_else(|| invalid("missing Q-table dimensions"))?
+            .split_whitespace()
+            .map(|dim| dim.parse::<usize>())
+            .collect::<Result<Vec<usize>, _>>()
+            .map_err(|_| invalid("invalid Q-table dimensions"))?;
+        if dims != vec![self.get_num_states(), Q_NUM_ACTIONS] {
+            return Err(invalid("Q-table dimensions do not match the building"));
+        }
+
+        //Parse the Q-values
+        let mut q_table: Vec<f64> = Vec::with_capacity(self.q_table.len());
+        for line in lines {
+            for value in line.split_whitespace() {
+                q_table.push(value.parse::<f64>().map_err(|_| invalid("invalid Q-value"))?);
+            }
+        }
+        if q_table.len() != self.q_table.len() {
+            return Err(invalid("Q-table has the wrong number of values"));
+        }
+
+        //Update the Q-table
+        self.q_table = q_table;
+        Ok(())
+    }
+}
+
+//Implement the ElevatorController trait for the QLearningController
+impl ElevatorController for QLearningController {
+    /// Return a boolean signifying whether the controller can be upgraded or
+    /// not, that is, whether its exploration probability can be decreased further.
+    fn can_be_upgraded(&self) -> bool {
+        self.epsilon > self.epsilon_min
+    }
+
+    /// Upgrade the controller given an incrementation float, decreasing its
+    /// exploration probability by the incrementation down to its minimum.
+    fn upgrade(&mut self, incrementation: f64) {
+        self.epsilon = (self.epsilon - incrementation).max(self.epsilon_min);
+    }
+
+    /// Update the Q-values of each elevator's previous state and action given the
+    /// reward observed in the view, then choose each available elevator's next action
+    /// epsilon-greedily and command the elevator accordingly.
+    fn decide(&mut self, view: &BuildingView) -> Vec<ElevatorCommand> {
+        //If the number of elevators in the building has changed, then track them
+        while view.get_num_elevators() > self.prev_state_actions.len() {
+            self.prev_state_actions.push(None);
+        }
+
+        //Calculate the reward for the last time step
+        let reward: f64 = self.get_reward(view);
+
+        //Loop through the elevators in the building
+        let mut commands: Vec<ElevatorCommand> = Vec::new();
+        for (i, elevator) in view.elevators.iter().enumerate() {
+            //Skip elevators which are unavailable, forgetting their previous state
+            if !elevator.is_available() {
+                self.prev_state_actions[i] = None;
+                continue;
+            }
+
+            //Get the elevator's state, valid actions, and best action
+            let state: usize = self.get_state(view, i);
+            let valid_actions: Vec<usize> = self.get_valid_actions(view, i);
+            let (best_action, best_value): (usize, f64) = self.get_best_action(state, &valid_actions);
+
+            //Update the Q-value of the elevator's previous state and action
+            if self.learning {
+                if let Some((prev_state, prev_action)) = self.prev_state_actions[i] {
+                    let q_index: usize = prev_state * Q_NUM_ACTIONS + prev_action;
+                    let td_error: f64 = reward + (self.gamma * best_value) - self.q_table[q_index];
+                    self.q_table[q_index] += self.alpha * td_error;
+                }
+            }
+
+            //Choose the action epsilon-greedily
+            let action: usize = if self.learning && self.rng.gen::<f64>() < self.epsilon {
+                valid_actions[self.rng.gen_range(0_usize..valid_actions.len())]
+            } else {
+                best_action
+            };
+            self.prev_state_actions[i] = Some((state, action));
+
+            //Command the elevator according to the action
+            commands.push(match action {
+                1_usize => ElevatorCommand::GoToFloor { elevator_index: i, floor_index: elevator.floor_on + 1_usize },
+                2_usize => ElevatorCommand::GoToFloor { elevator_index: i, floor_index: elevator.floor_on - 1_usize },
+                _ => ElevatorCommand::OpenDoors { elevator_index: i }
+            });
+        }
+
+        //Return the commands
+        commands
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use super::*;
+
+    //Initialize a building for the controllers to learn on
+    fn new_building(num_floors: usize) -> Building {
+        Building::from(
+            num_floors,
+            2_usize,
+            1.0_f64,
+            100_usize,
+            10_usize,
+            5.0_f64,
+            2.5_f64,
+            0.5_f64
+        )
+    }
+
+    //Get a path in the temp directory for a Q-table written by a test
+    fn get_table_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("elevate_q_table_{}_{}.txt", name, std::process::id()))
+    }
 
-            //Update the elevator
-            let _new_floor_index = self.building.elevators[i].update_floor();
+    #[test]
+    fn q_table_round_trips_through_save_and_load() {
+        //Train a controller online so its Q-table is not all zeros
+        let mut building: Building = new_building(6_usize);
+        let mut controller: QLearningController = QLearningController::from(
+            &building, StdRng::seed_from_u64(0_u64), 0.1_f64, 0.95_f64, 1.0_f64, 0.05_f64
+        );
+        let mut rng: StdRng = StdRng::seed_from_u64(1_u64);
+        for _ in 0_usize..200_usize {
+            building.begin_step(&mut rng);
+            let commands: Vec<ElevatorCommand> = controller.decide(&building.get_view());
+            building.apply_elevator_commands(&commands);
+            building.end_step().unwrap();
         }
+        assert!(controller.q_table.iter().any(|value| *value != 0.0_f64));
+
+        //Save the table, then load it into a fresh controller
+        let path: PathBuf = get_table_path("round_trip");
+        controller.save_table(&path).unwrap();
+        let mut loaded: QLearningController = QLearningController::from(
+            &building, StdRng::seed_from_u64(2_u64), 0.1_f64, 0.95_f64, 1.0_f64, 0.05_f64
+        );
+        let result: io::Result<()> = loaded.load_table(&path);
+        let _ = fs::remove_file(&path);
+        result.unwrap();
+        assert_eq!(loaded.q_table, controller.q_table);
+    }
+
+    #[test]
+    fn q_table_for_another_building_is_rejected() {
+        let controller: QLearningController = QLearningController::from(
+            &new_building(6_usize), StdRng::seed_from_u64(0_u64), 0.1_f64, 0.95_f64, 1.0_f64, 0.05_f64
+        );
+        let path: PathBuf = get_table_path("mismatch");
+        controller.save_table(&path).unwrap();
+        let mut other: QLearningController = QLearningController::from(
+            &new_building(4_usize), StdRng::seed_from_u64(0_u64), 0.1_f64, 0.95_f64, 1.0_f64, 0.05_f64
+        );
+        let result: io::Result<()> = other.load_table(&path);
+        let _ = fs::remove_file(&path);
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
+        assert!(other.q_table.iter().all(|value| *value == 0.0_f64));
     }
 }
\ No newline at end of file
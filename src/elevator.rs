@@ -1,15 +1,55 @@
 //Import standard/imported modules
-use rand::Rng;
+use rand::RngCore;
+use serde::Serialize;
 
 //Import source modules
-use crate::person::Person;
+use crate::person::{Person, PERSON_MASS};
 use crate::people::People;
+use crate::validation::ViolationKind;
+
+/// # `ElevatorStatus` enum
+///
+/// An `ElevatorStatus` describes whether an `Elevator` is available to be dispatched
+/// by an `ElevatorController`.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum ElevatorStatus {
+    /// The elevator is in service and available to be dispatched
+    InService,
+    /// The elevator is out of service for scheduled maintenance, for the given number
+    /// of remaining time steps
+    Maintenance(usize),
+    /// The elevator has broken down, and will be repaired after the given number of
+    /// remaining time steps
+    BrokenDown(usize),
+    /// The elevator is in independent service, meaning it is taken out of dispatch and
+    /// parked on its current floor.  It is not moved by an `ElevatorController`, but
+    /// people may still board and get off it there.
+    IndependentService
+}
+
+/// # `LoadLevel` enum
+///
+/// A `LoadLevel` classifies how full an `Elevator` is, so that renderers and exporters
+/// color elevators consistently.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LoadLevel {
+    /// The elevator is less than half full
+    Light,
+    /// The elevator is at least half full, but less than 90% full
+    Partial,
+    /// The elevator is at least 90% full
+    Full,
+    /// The elevator is not available to be dispatched
+    Unavailable
+}
 
 /// # Elevator struct
 ///
 /// An `Elevator` is aggregated by buildings, and transports people between floors.
 /// The `Elevator` struct generally should not be directly instantiated; instead it
 /// should be managed via the `Building` type and `ElevatorController` implementations.
+/// The `num_boarded` and `num_alighted` fields count the people who boarded and got
+/// off the elevator during the building's last exchange of people.
 #[derive(Clone)]
 pub struct Elevator {
     pub floor_on: usize,
@@ -17,9 +57,20 @@ pub struct Elevator {
     pub stopped: bool,
     pub people: Vec<Person>,
     pub capacity: usize,
+    pub rated_load: f64,
+    pub rated_volume: f64,
     pub energy_up: f64,
     pub energy_down: f64,
-    pub energy_coef: f64
+    pub energy_coef: f64,
+    pub energy_idle: f64,
+    pub energy_sleep: f64,
+    pub idle_steps: usize,
+    pub sleeping: bool,
+    pub wake_delay: usize,
+    pub wake_steps: usize,
+    pub status: ElevatorStatus,
+    pub num_boarded: usize,
+    pub num_alighted: usize
 }
 
 /// # Elevator type implementation
@@ -29,8 +80,12 @@ pub struct Elevator {
 impl Elevator {
     /// Initialize a new elevator given the elevator's energy spent moving up, energy
     /// spent moving down, and energy coefficient (additional energy spent per person
-    /// transported).  The elevator is initialized stopped on the first floor with no
-    /// people.
+    /// transported, where a person is counted as `PERSON_MASS` kg of load).  The
+    /// elevator is initialized stopped on the first floor with no people.  Its rated
+    /// load is initialized to its capacity times `PERSON_MASS`, and its rated volume
+    /// is unlimited.  Both can be updated via the `Elevators` trait's
+    /// `update_rated_loads` function.  Its standby energy is initialized to `0.0_f64`,
+    /// and can be updated via the `Elevators` trait's `update_standby_energy` function.
     ///
     /// ### Example
     ///
@@ -48,21 +103,38 @@ impl Elevator {
             stopped: true,
             people: Vec::new(),
             capacity: capacity,
+            rated_load: capacity as f64 * PERSON_MASS,
+            rated_volume: f64::INFINITY,
             energy_up: energy_up,
             energy_down: energy_down,
-            energy_coef: energy_coef
+            energy_coef: energy_coef,
+            energy_idle: 0.0_f64,
+            energy_sleep: 0.0_f64,
+            idle_steps: 0_usize,
+            sleeping: false,
+            wake_delay: 0_usize,
+            wake_steps: 0_usize,
+            status: ElevatorStatus::InService,
+            num_boarded: 0_usize,
+            num_alighted: 0_usize
         }
     }
     
-    /// Calculate the total energy spent (as an `f64`) while the elevator is moving.
-    /// If the elevator is not moving then return `0.0_f64`.
+    /// Calculate the total energy spent (as an `f64`) by the elevator during a time
+    /// step.  If the elevator is out of service then return `0.0_f64`, if it is asleep
+    /// then return its sleep energy, and if it is stopped but awake then return its idle
+    /// (standby) energy.
     pub fn get_energy_spent(&mut self) -> f64 {
-        let energy_spent = if self.stopped {
+        let energy_spent = if self.is_out_of_service() {
                 0.0_f64
+            } else if self.sleeping {
+                self.energy_sleep
+            } else if self.stopped {
+                self.energy_idle
             } else if self.moving_up {
-                self.energy_up + (self.energy_coef * (self.people.len() as f64))
+                self.energy_up + (self.energy_coef * (self.get_load() / PERSON_MASS))
             } else {
-                self.energy_down + (self.energy_coef * (self.people.len() as f64))
+                self.energy_down + (self.energy_coef * (self.get_load() / PERSON_MASS))
             };
         energy_spent
     }
@@ -72,11 +144,176 @@ impl Elevator {
         self.capacity - self.people.get_num_people()
     }
 
+    /// Calculate the total load (in kg) currently carried by the elevator
+    pub fn get_load(&self) -> f64 {
+        self.people.get_aggregate_mass()
+    }
+
+    /// Calculate the free load (in kg) for the elevator with respect to its rated
+    /// load
+    pub fn get_free_load(&self) -> f64 {
+        let free_load: f64 = self.rated_load - self.get_load();
+        if free_load > 0.0_f64 { free_load } else { 0.0_f64 }
+    }
+
+    /// Calculate the free volume (in cubic meters) for the elevator with respect to
+    /// its rated volume
+    pub fn get_free_volume(&self) -> f64 {
+        let free_volume: f64 = self.rated_volume - self.people.get_aggregate_volume();
+        if free_volume > 0.0_f64 { free_volume } else { 0.0_f64 }
+    }
+
+    /// Determine whether a person fits on the elevator given its free capacity, its
+    /// free load, and its free volume
+    pub fn can_fit(&self, pers: &Person) -> bool {
+        self.get_free_capacity() > 0_usize &&
+        pers.mass <= self.get_free_load() &&
+        pers.volume <= self.get_free_volume()
+    }
+
+    /// Determine whether the elevator is available to be dispatched by an
+    /// `ElevatorController`, that is, whether it is in service.
+    pub fn is_available(&self) -> bool {
+        self.status == ElevatorStatus::InService
+    }
+
+    /// Get the elevator's `LoadLevel` given how full it is, or `Unavailable` if it is
+    /// not available to be dispatched
+    pub fn get_load_level(&self) -> LoadLevel {
+        if !self.is_available() {
+            return LoadLevel::Unavailable;
+        }
+        let load_frac: f64 = if self.capacity > 0_usize {
+            self.get_num_people() as f64 / self.capacity as f64
+        } else {
+            0.0_f64
+        };
+        if load_frac < 0.5_f64 {
+            LoadLevel::Light
+        } else if load_frac < 0.9_f64 {
+            LoadLevel::Partial
+        } else {
+            LoadLevel::Full
+        }
+    }
+
+    /// Determine whether the elevator is out of service, that is, under maintenance or
+    /// broken down, in which case it cannot move or carry people.
+    pub fn is_out_of_service(&self) -> bool {
+        matches!(self.status, ElevatorStatus::Maintenance(_) | ElevatorStatus::BrokenDown(_))
+    }
+
+    /// Take the elevator out of service given its new status, and return the people on
+    /// board as a `Vec<Person>` so that they can be evacuated onto the elevator's current
+    /// floor.  The elevator is stopped and woken from its sleep state.
+    pub fn take_out_of_service(&mut self, status: ElevatorStatus) -> Vec<Person> {
+        self.status = status;
+        self.stopped = true;
+        self.sleeping = false;
+        self.wake_steps = 0_usize;
+        self.idle_steps = 0_usize;
+        std::mem::take(&mut self.people)
+    }
+
+    /// Update the elevator's status by counting down the time steps remaining in its
+    /// maintenance or repair.  Once none remain, the elevator returns to service.
+    pub fn update_status(&mut self) {
+        self.status = match self.status {
+            ElevatorStatus::Maintenance(steps) | ElevatorStatus::BrokenDown(steps) if steps <= 1_usize => {
+                ElevatorStatus::InService
+            },
+            ElevatorStatus::Maintenance(steps) => ElevatorStatus::Maintenance(steps - 1_usize),
+            ElevatorStatus::BrokenDown(steps) => ElevatorStatus::BrokenDown(steps - 1_usize),
+            status => status
+        };
+    }
+
+    /// Determine whether the elevator is idle, that is, stopped with no people on
+    /// board.
+    pub fn is_idle(&self) -> bool {
+        self.stopped && self.people.is_empty()
+    }
+
+    /// Determine whether the elevator is waking up from its low-power sleep state,
+    /// in which case it cannot move or exchange people until its wake-up delay has
+    /// elapsed.
+    pub fn is_waking(&self) -> bool {
+        self.wake_steps > 0_usize
+    }
+
+    /// Put the elevator into its low-power sleep state given the number of time
+    /// steps it will take to wake up again.  The elevator is only put to sleep if
+    /// it is idle.
+    pub fn sleep(&mut self, wake_delay: usize) {
+        if !self.is_idle() {
+            return;
+        }
+        self.sleeping = true;
+        self.wake_delay = wake_delay;
+    }
+
+    /// Wake the elevator from its low-power sleep state.  The elevator will remain
+    /// stopped for its wake-up delay before it is able to move again.
+    pub fn wake(&mut self) {
+        if !self.sleeping {
+            return;
+        }
+        self.sleeping = false;
+        self.wake_steps = self.wake_delay;
+        self.idle_steps = 0_usize;
+    }
+
+    /// Update the number of consecutive time steps the elevator has been idle, and
+    /// put the elevator to sleep if it has been idle for at least `sleep_after` time
+    /// steps.  If `sleep_after` is `None` then the elevator never sleeps.
+    pub fn update_idle(&mut self, sleep_after: Option<usize>, wake_delay: usize) {
+        //If the elevator is not idle or not in service, then reset its idle counter
+        if !self.is_idle() || self.is_waking() || !self.is_available() {
+            self.idle_steps = 0_usize;
+            return;
+        }
+
+        //If the elevator is idle and awake, then increment its idle counter
+        if !self.sleeping {
+            self.idle_steps += 1_usize;
+        }
+
+        //If the elevator has been idle long enough, then put it to sleep
+        if let Some(max_idle_steps) = sleep_after {
+            if !self.sleeping && self.idle_steps >= max_idle_steps {
+                self.sleep(wake_delay);
+            }
+        }
+    }
+
     /// Update the `stopped` and `moving_up` properties of the elevator given a
     /// destination floor for the elevator.  The properties will be set such that
     /// the elevator moves in the direction of the provided floor with respect to
-    /// its current floor when updated.
+    /// its current floor when updated.  If the elevator is asleep and the floor is
+    /// not its current floor, then it is woken up, and it remains stopped until its
+    /// wake-up delay has elapsed.  If the elevator is out of service, then it remains
+    /// stopped.
     pub fn update_direction(&mut self, floor_to: usize) {
+        //If the elevator is out of service, then it remains stopped
+        if self.is_out_of_service() {
+            self.stopped = true;
+            return;
+        }
+
+        //If the elevator is asleep and needs to move, then wake it up
+        if self.sleeping && floor_to != self.floor_on {
+            self.wake();
+        }
+
+        //If the elevator is asleep or waking up, then it remains stopped
+        if self.sleeping || self.is_waking() {
+            if self.is_waking() {
+                self.wake_steps -= 1_usize;
+            }
+            self.stopped = true;
+            return;
+        }
+
         //If the elevator is not on its destination floor, then move toward it
         if floor_to > self.floor_on {
             self.stopped = false;
@@ -93,8 +330,14 @@ impl Elevator {
     /// Use the `stopped` and `moving_up` properties of the elevator to update the
     /// elevator's floor index.  If stopped, then no change.  If moving up then
     /// increment the `floor_on` by `1_usize`.  If moving down then decrement the
-    /// `floor_on` by `1_usize`.
+    /// `floor_on` by `1_usize`, unless the elevator is on the bottom floor, in which
+    /// case it is stopped instead.
     pub fn update_floor(&mut self) -> usize {
+        //If the elevator is moving down from the bottom floor, then stop it
+        if !self.stopped && !self.moving_up && self.floor_on == 0_usize {
+            self.stopped = true;
+        }
+
         //If the elevator is stopped, then return early
         if self.stopped {
             return self.floor_on;
@@ -116,6 +359,79 @@ impl Elevator {
         self.floor_on
     }
     
+    /// Check the structural invariants of the elevator given its index in the building
+    /// and the number of floors in the building.  Returns the first violation found,
+    /// if any.
+    pub fn validate(&self, elevator_index: usize, num_floors: usize) -> Result<(), ViolationKind> {
+        //Check that the elevator is within the building
+        if self.floor_on >= num_floors {
+            return Err(ViolationKind::ElevatorOutOfBounds {
+                elevator_index: elevator_index,
+                floor_on: self.floor_on,
+                num_floors: num_floors
+            });
+        }
+
+        //Check that the elevator is within its capacity and rated load
+        if self.people.len() > self.capacity {
+            return Err(ViolationKind::ElevatorOverCapacity {
+                elevator_index: elevator_index,
+                num_people: self.people.len(),
+                capacity: self.capacity
+            });
+        }
+        if self.get_load() > self.rated_load + f64::EPSILON * self.rated_load.abs() {
+            return Err(ViolationKind::ElevatorOverLoad {
+                elevator_index: elevator_index,
+                load: self.get_load(),
+                rated_load: self.rated_load
+            });
+        }
+
+        //Check that each person is on the elevator's floor and going to a real floor
+        for (i, pers) in self.people.iter().enumerate() {
+            if pers.floor_on != self.floor_on {
+                return Err(ViolationKind::ElevatorPersonMismatch {
+                    elevator_index: elevator_index,
+                    person_index: i,
+                    person_floor_on: pers.floor_on,
+                    elevator_floor_on: self.floor_on
+                });
+            }
+            if pers.floor_to >= num_floors {
+                return Err(ViolationKind::DestinationOutOfBounds {
+                    floor_index: self.floor_on,
+                    person_index: i,
+                    floor_to: pers.floor_to,
+                    num_floors: num_floors
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Add people into the elevator while they fit within its capacity, rated load, and
+    /// rated volume, and return anyone who did not fit as a `Vec<Person>`.
+    pub fn try_extend<T: IntoIterator<Item=Person>>(&mut self, iter: T) -> Vec<Person> {
+        //Initialize a vector of people for the people rejected by the elevator
+        let mut people_rejected: Vec<Person> = Vec::new();
+
+        //Add people into the elevator while they fit
+        for pers in iter {
+            //Reject anyone who would exceed the capacity, rated load, or volume
+            if !self.can_fit(&pers) {
+                people_rejected.push(pers);
+                continue;
+            }
+
+            //Add a person
+            self.people.push(pers);
+        }
+
+        //Return the vector of people rejected
+        people_rejected
+    }
+
     /// If there are people on the elevator, this returns the nearest destination
     /// floor among those people represented as a length-2 tuple of `usize`s.  The
     /// first element is the destination floor, and the second is the distance to
@@ -197,24 +513,19 @@ impl Elevator {
 
 //Implement the extend trait for the elevator struct
 impl Extend<Person> for Elevator {
+    /// Add people into the elevator, who are expected to fit within its capacity, rated
+    /// load, and rated volume.  Panics in debug builds if anyone does not fit, since
+    /// they would otherwise vanish; use `try_extend` to get them back instead.
     fn extend<T: IntoIterator<Item=Person>>(&mut self, iter: T) {
-        //Add people into the elevator until at capacity
-        for pers in iter {
-            //Break if we reach capacity
-            if self.people.get_num_people() == self.capacity {
-                break;
-            }
-
-            //Add a person
-            self.people.push(pers);
-        }
+        let people_rejected: Vec<Person> = self.try_extend(iter);
+        debug_assert!(people_rejected.is_empty(), "{} people did not fit in the elevator", people_rejected.len());
     }
 }
 
 //Implement the people trait for the elevator struct
 impl People for Elevator {
     /// Generates the number of people among the collection of people who will tip.
-    fn gen_num_tips(&self, rng: &mut impl Rng) -> usize {
+    fn gen_num_tips(&self, rng: &mut dyn RngCore) -> usize {
         self.people.gen_num_tips(rng)
     }
 
@@ -244,6 +555,17 @@ impl People for Elevator {
         self.people.get_aggregate_wait_time()
     }
 
+    /// Aggregates the total mass (in kg) of the people and returns it as an f64.
+    fn get_aggregate_mass(&self) -> f64 {
+        self.people.get_aggregate_mass()
+    }
+
+    /// Aggregates the total volume (in cubic meters) of the luggage and carts carried
+    /// by the people and returns it as an f64.
+    fn get_aggregate_volume(&self) -> f64 {
+        self.people.get_aggregate_volume()
+    }
+
     /// Determines whether anyone in the collection of people are going to a given floor,
     /// and returns a bool which is true if so, and false if not.
     fn are_people_waiting(&self) -> bool {
@@ -267,4 +589,10 @@ impl People for Elevator {
     fn reset_wait_times(&mut self) {
         self.people.reset_wait_times()
     }
+
+    /// Evacuates all people on the elevator by directing them to the given exit floor
+    /// and marking them as leaving.
+    fn evacuate(&mut self, floor_to: usize) {
+        self.people.evacuate(floor_to)
+    }
 }
\ No newline at end of file
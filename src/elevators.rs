@@ -1,5 +1,5 @@
 //Import source modules
-use crate::elevator::Elevator;
+use crate::elevator::{Elevator, ElevatorStatus};
 use crate::people::People;
 
 /// # `Elevators` trait
@@ -18,6 +18,20 @@ pub trait Elevators {
     fn append_elevator(&mut self, capacity: usize, energy_up: f64, energy_down: f64, energy_coef: f64);
 
     fn update_capacities(&mut self, capacity: usize);
+
+    fn update_rated_loads(&mut self, rated_load: f64, rated_volume: f64);
+
+    fn update_standby_energy(&mut self, energy_idle: f64, energy_sleep: f64);
+
+    fn update_idle(&mut self, sleep_after: Option<usize>, wake_delay: usize);
+
+    fn get_statuses(&self) -> Vec<ElevatorStatus>;
+
+    fn get_available(&self) -> Vec<bool>;
+
+    fn update_statuses(&mut self);
+
+    fn evacuate(&mut self, floor_to: usize);
 }
 
 //Implementation of elevators trait for Vec<Elevators>
@@ -106,4 +120,92 @@ impl Elevators for Vec<Elevator> {
             }
         }
     }
+
+    /// Updates the rated load (in kg) and rated volume (in cubic meters) across each
+    /// of the elevators
+    fn update_rated_loads(&mut self, rated_load: f64, rated_volume: f64) {
+        //Ensure that the rated load and volume are not less than the
+        //current load and volume on any given elevator
+        let can_update_rated_loads: bool = {
+            let mut tmp_can_update_rated_loads: bool = true;
+            for elevator in self.iter() {
+                if rated_load < elevator.get_load() || rated_volume < elevator.get_aggregate_volume() {
+                    tmp_can_update_rated_loads = false;
+                    break;
+                }
+            }
+            tmp_can_update_rated_loads
+        };
+
+        //If the rated loads can be updated across all elevators,
+        //then update the rated loads
+        if can_update_rated_loads {
+            for elevator in self.iter_mut() {
+                elevator.rated_load = rated_load;
+                elevator.rated_volume = rated_volume;
+            }
+        }
+    }
+
+    /// Updates the idle (standby) energy and the sleep energy across each of the
+    /// elevators
+    fn update_standby_energy(&mut self, energy_idle: f64, energy_sleep: f64) {
+        for elevator in self.iter_mut() {
+            elevator.energy_idle = energy_idle;
+            elevator.energy_sleep = energy_sleep;
+        }
+    }
+
+    /// For each elevator, update its idle counter and put it to sleep if it has
+    /// been idle for at least `sleep_after` time steps.
+    fn update_idle(&mut self, sleep_after: Option<usize>, wake_delay: usize) {
+        for elevator in self.iter_mut() {
+            elevator.update_idle(sleep_after, wake_delay);
+        }
+    }
+
+    /// Get the status of each of the elevators
+    fn get_statuses(&self) -> Vec<ElevatorStatus> {
+        //Initialize a vector of statuses
+        let mut statuses: Vec<ElevatorStatus> = Vec::new();
+
+        //Loop through the elevators and push their status
+        for elevator in self.iter() {
+            statuses.push(elevator.status);
+        }
+
+        //Return the statuses
+        statuses
+    }
+
+    /// Get whether each of the elevators is available to be dispatched by an
+    /// `ElevatorController`
+    fn get_available(&self) -> Vec<bool> {
+        //Initialize a vector of booleans
+        let mut available: Vec<bool> = Vec::new();
+
+        //Loop through the elevators and push whether they are available
+        for elevator in self.iter() {
+            available.push(elevator.is_available());
+        }
+
+        //Return the booleans
+        available
+    }
+
+    /// For each elevator, count down the time steps remaining in its maintenance or
+    /// repair, returning it to service once none remain.
+    fn update_statuses(&mut self) {
+        for elevator in self.iter_mut() {
+            elevator.update_status();
+        }
+    }
+
+    /// For each elevator, evacuate the people on the elevator by directing them to the
+    /// given exit floor and marking them as leaving.
+    fn evacuate(&mut self, floor_to: usize) {
+        for elevator in self.iter_mut() {
+            elevator.evacuate(floor_to);
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,116 @@
+//Import standard/imported modules
+use serde::Serialize;
+
+/// # `BuildingMode` enum
+///
+/// A `BuildingMode` is a building-level mode switch.  While a `Building` is in any
+/// mode other than `Normal`, its elevators are controlled by the building itself, and
+/// the active `ElevatorController` is overridden.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum BuildingMode {
+    /// Elevators are controlled by the active `ElevatorController`
+    Normal,
+    /// Fire-service recall: every elevator returns to the recall floor without stopping,
+    /// hall calls are cancelled, and passengers get off at the recall floor
+    FireRecall { recall_floor: usize },
+    /// Emergency evacuation: everyone in the building is moved to the exit floor as fast
+    /// as possible using the elevators
+    Evacuation
+}
+
+/// # `EvacuationReport` struct
+///
+/// An `EvacuationReport` tracks the progress of an emergency evacuation, including the
+/// time step at which it started, the time step at which the building was cleared, and
+/// the number of time steps it took to clear each floor.  Floors are reported by their
+/// names.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EvacuationReport {
+    pub start_step: usize,
+    pub end_step: Option<usize>,
+    pub floor_clearance_times: Vec<Option<usize>>,
+    pub floor_names: Vec<String>
+}
+
+/// # `EvacuationReport` type implementation
+///
+/// The following functions are used by the `Building` type to update an evacuation
+/// report, and by users to summarize it.
+impl EvacuationReport {
+    /// Initialize a new evacuation report given the time step at which the evacuation
+    /// started and the names of the building's floors.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let start_step: usize = 100_usize;
+    /// let floor_names: Vec<String> = vec![
+    ///     String::from("-1"),
+    ///     String::from("0"),
+    ///     String::from("1")
+    /// ];
+    /// let my_report: EvacuationReport = EvacuationReport::from(start_step, floor_names);
+    /// ```
+    pub fn from(start_step: usize, floor_names: Vec<String>) -> EvacuationReport {
+        EvacuationReport {
+            start_step: start_step,
+            end_step: None,
+            floor_clearance_times: vec![None; floor_names.len()],
+            floor_names: floor_names
+        }
+    }
+
+    /// Get the total number of time steps the evacuation took, or `None` if the
+    /// building has not been cleared yet.
+    pub fn get_total_time(&self) -> Option<usize> {
+        self.end_step.map(|end_step| end_step - self.start_step)
+    }
+
+    /// Update the report given the current time step, the number of people on each
+    /// floor, and the number of people on the elevators.  Floors are marked cleared
+    /// the first time they are empty, and the evacuation ends once everyone is out.
+    pub fn update(&mut self, time_step: usize, num_people_per_floor: &[usize], num_people_on_elevators: usize) {
+        //Mark any newly cleared floors
+        for (i, num_people) in num_people_per_floor.iter().enumerate() {
+            if *num_people == 0_usize && self.floor_clearance_times[i].is_none() {
+                self.floor_clearance_times[i] = Some(time_step - self.start_step);
+            }
+        }
+
+        //If everyone is out of the building, then the evacuation is over
+        let num_people: usize = num_people_per_floor.iter().sum::<usize>() + num_people_on_elevators;
+        if num_people == 0_usize && self.end_step.is_none() {
+            self.end_step = Some(time_step);
+        }
+    }
+}
+
+//Display trait implementation for an evacuation report
+impl std::fmt::Display for EvacuationReport {
+    /// Format an `EvacuationReport` as a string.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// println!("{}", my_report);
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        //Format the total evacuation time
+        let mut report: String = match self.get_total_time() {
+            Some(total_time) => format!("Total evacuation time:\t{}", total_time),
+            None => String::from("Total evacuation time:\tin progress")
+        };
+
+        //Format the clearance time of each floor
+        for (floor_name, clearance_time) in self.floor_names.iter().zip(self.floor_clearance_times.iter()).rev() {
+            let clearance_str: String = match clearance_time {
+                Some(clearance_time) => format!("Floor {} cleared after:\t{}", floor_name, clearance_time),
+                None => format!("Floor {} cleared after:\tin progress", floor_name)
+            };
+            report = [report, clearance_str].join("\n");
+        }
+
+        //Format the string and return
+        f.write_str(&report)
+    }
+}
\ No newline at end of file
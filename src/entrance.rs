@@ -0,0 +1,58 @@
+//Import standard/imported modules
+use rand::RngCore;
+use rand::distributions::Distribution;
+use serde::Serialize;
+use statrs::distribution::Poisson;
+
+/// # `Entrance` struct
+///
+/// An `Entrance` is one of a building's entrance floors, such as a street lobby, a
+/// metro connection, or a parking garage.  Each entrance has its own Poisson arrival
+/// process with its own expected number of arrivals per time step, and counts the
+/// people who arrived, balked, were rejected, and departed through it.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Entrance {
+    pub floor_index: usize,
+    pub p_in: f64,
+    pub tot_arrived: usize,
+    pub tot_balked: usize,
+    pub tot_rejected: usize,
+    pub tot_departed: usize,
+    #[serde(skip)]
+    dst_in: Option<Poisson>
+}
+
+//Implement the Entrance interface
+impl Entrance {
+    /// Initialize a new Entrance given the index of its floor and its expected number
+    /// of arrivals per time step.  An entrance expecting no arrivals never generates
+    /// any.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let floor_index: usize = 0_usize;
+    /// let p_in: f64 = 0.5_f64;
+    /// let my_entrance: Entrance = Entrance::from(floor_index, p_in);
+    /// ```
+    pub fn from(floor_index: usize, p_in: f64) -> Entrance {
+        Entrance {
+            floor_index: floor_index,
+            p_in: p_in,
+            tot_arrived: 0_usize,
+            tot_balked: 0_usize,
+            tot_rejected: 0_usize,
+            tot_departed: 0_usize,
+            dst_in: if p_in > 0.0_f64 { Some(Poisson::new(p_in).unwrap()) } else { None }
+        }
+    }
+
+    /// Generate the number of people arriving through the entrance during the current
+    /// time step by sampling its Poisson distribution
+    pub fn gen_num_arrivals(&self, mut rng: &mut dyn RngCore) -> usize {
+        match &self.dst_in {
+            Some(dst_in) => dst_in.sample(&mut rng) as usize,
+            None => 0_usize
+        }
+    }
+}
\ No newline at end of file
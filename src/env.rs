@@ -0,0 +1,213 @@
+//Import standard/imported modules
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+//Import source modules
+use crate::building::Building;
+use crate::people::People;
+use crate::elevators::Elevators;
+use crate::emergency::BuildingMode;
+use crate::command::ElevatorCommand;
+use crate::view::BuildingView;
+use crate::validation::InvariantViolation;
+
+/// # `RewardWeights` struct
+///
+/// A `RewardWeights` configures the reward returned by an `Env` each time step.  The
+/// reward is the tips collected during the time step times the `tips` weight, minus
+/// the number of people waiting times the `wait_time` weight, minus the energy spent
+/// by the elevators times the `energy` weight.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RewardWeights {
+    pub wait_time: f64,
+    pub energy: f64,
+    pub tips: f64
+}
+
+//Implement the RewardWeights interface
+impl RewardWeights {
+    /// Initialize a new RewardWeights given the weights of the waiting time, the
+    /// energy spent, and the tips collected.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let my_weights: RewardWeights = RewardWeights::from(1.0_f64, 0.1_f64, 0.5_f64);
+    /// ```
+    pub fn from(wait_time: f64, energy: f64, tips: f64) -> RewardWeights {
+        RewardWeights {
+            wait_time: wait_time,
+            energy: energy,
+            tips: tips
+        }
+    }
+}
+
+//Implement the Default trait for the RewardWeights
+impl Default for RewardWeights {
+    /// Initialize a RewardWeights which only penalizes waiting time
+    fn default() -> Self {
+        RewardWeights::from(1.0_f64, 0.0_f64, 0.0_f64)
+    }
+}
+
+/// # `StepInfo` struct
+///
+/// A `StepInfo` holds diagnostic information about a single `Env` time step, which is
+/// not part of the observation or reward.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StepInfo {
+    pub time_step: usize,
+    pub num_people_waiting: usize,
+    pub energy_spent: f64,
+    pub tips: f64,
+    pub commands_rejected: Vec<ElevatorCommand>,
+    pub violation: Option<InvariantViolation>
+}
+
+/// # `Env` struct
+///
+/// An `Env` is a gym-style reinforcement learning environment wrapping a `Building`.
+/// Each episode starts from a clone of the building the environment was created with.
+/// Each time step the agent observes a fixed-size numeric encoding of the building's
+/// floors, calls, and elevators, and acts by returning a set of `ElevatorCommand`s.
+/// An episode is done after `max_steps` time steps, or as soon as the building
+/// violates one of its invariants while in debug mode.
+pub struct Env {
+    pub building: Building,
+    pub reward_weights: RewardWeights,
+    pub max_steps: usize,
+    initial_building: Building,
+    rng: StdRng
+}
+
+//Implement the Env interface
+impl Env {
+    /// Initialize a new Env given the building each episode starts from, the reward
+    /// weights, and the maximum number of time steps per episode.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let my_building: Building = Building::from(
+    ///     4_usize,
+    ///     2_usize,
+    ///     0.5_f64,
+    ///     5.0_f64,
+    ///     2.5_f64,
+    ///     0.5_f64
+    /// );
+    /// let my_env: Env = Env::from(my_building, RewardWeights::default(), 1000_usize);
+    /// ```
+    pub fn from(building: Building, reward_weights: RewardWeights, max_steps: usize) -> Env {
+        Env {
+            building: building.clone(),
+            reward_weights: reward_weights,
+            max_steps: max_steps,
+            initial_building: building,
+            rng: StdRng::seed_from_u64(0_u64)
+        }
+    }
+
+    /// Get the size of the observation vector, which depends on the number of floors
+    /// and elevators in the building.  Each floor is encoded as 3 values, and each
+    /// elevator as 5 values plus one value per floor.
+    pub fn get_observation_size(&self) -> usize {
+        let num_floors: usize = self.building.floors.len();
+        let num_elevators: usize = self.building.elevators.len();
+        (3_usize * num_floors) + num_elevators * (5_usize + num_floors)
+    }
+
+    /// Get the current observation of the building as a fixed-size vector of f64s.
+    /// Each floor is encoded as the fraction of its capacity waiting on it, then
+    /// whether it has an up and a down hall call.  Each elevator is then encoded as
+    /// its floor as a fraction of the top floor, whether it is moving up, whether it
+    /// is stopped, whether it is available, the fraction of its capacity in use, and
+    /// whether it has a car call for each floor.
+    pub fn get_observation(&self) -> Vec<f64> {
+        //Get a view of the building
+        let view: BuildingView = self.building.get_view();
+        let num_floors: usize = view.get_num_floors();
+        let top_floor: f64 = if num_floors > 1_usize { (num_floors - 1_usize) as f64 } else { 1.0_f64 };
+        let mut observation: Vec<f64> = Vec::with_capacity(self.get_observation_size());
+
+        //Encode the floors and their hall calls
+        for floor in view.floors.iter() {
+            let capacity: f64 = if floor.capacity > 0_usize { floor.capacity as f64 } else { 1.0_f64 };
+            observation.push(floor.num_people_waiting as f64 / capacity);
+            observation.push(if floor.hall_call_up { 1.0_f64 } else { 0.0_f64 });
+            observation.push(if floor.hall_call_down { 1.0_f64 } else { 0.0_f64 });
+        }
+
+        //Encode the elevators and their car calls
+        for elevator in view.elevators.iter() {
+            let capacity: f64 = if elevator.capacity > 0_usize { elevator.capacity as f64 } else { 1.0_f64 };
+            observation.push(elevator.floor_on as f64 / top_floor);
+            observation.push(if elevator.moving_up { 1.0_f64 } else { 0.0_f64 });
+            observation.push(if elevator.stopped { 1.0_f64 } else { 0.0_f64 });
+            observation.push(if elevator.is_available() { 1.0_f64 } else { 0.0_f64 });
+            observation.push(elevator.num_people as f64 / capacity);
+            for i in 0..num_floors {
+                observation.push(if elevator.are_people_going_to_floor(i) { 1.0_f64 } else { 0.0_f64 });
+            }
+        }
+
+        //Return the observation
+        observation
+    }
+
+    /// Reset the environment to the start of a new episode, restoring the initial
+    /// building and seeding the environment's rng, and return the first observation.
+    pub fn reset(&mut self, seed: u64) -> Vec<f64> {
+        self.building = self.initial_building.clone();
+        self.rng = StdRng::seed_from_u64(seed);
+        self.get_observation()
+    }
+
+    /// Run a single time step given the agent's actions as a set of elevator commands,
+    /// and return the next observation, the reward, whether the episode is done, and
+    /// diagnostic information about the step.  If the building is in an emergency
+    /// mode, then the actions are ignored and the building updates its own elevators.
+    pub fn step(&mut self, actions: &[ElevatorCommand]) -> (Vec<f64>, f64, bool, StepInfo) {
+        //Begin the time step and record the tips collected so far
+        let tot_tips: f64 = self.building.tot_tips;
+        self.building.begin_step(&mut self.rng);
+
+        //Apply the actions to the building
+        let commands_rejected: Vec<ElevatorCommand> = if self.building.mode == BuildingMode::Normal {
+            self.building.apply_elevator_commands(actions)
+        } else {
+            self.building.update_elevators_emergency();
+            actions.to_vec()
+        };
+
+        //Measure the people waiting, the energy spent, and the tips collected
+        let num_people_waiting: usize = self.building.floors.iter()
+            .map(|floor| floor.get_num_people_waiting())
+            .sum::<usize>() + self.building.elevators.iter()
+            .map(|elevator| elevator.get_num_people_waiting())
+            .sum::<usize>();
+        let energy_spent: f64 = self.building.elevators.get_energy_spent();
+        let tips: f64 = self.building.tot_tips - tot_tips;
+
+        //End the time step
+        let violation: Option<InvariantViolation> = self.building.end_step().err();
+
+        //Calculate the reward and whether the episode is done
+        let reward: f64 = (self.reward_weights.tips * tips) -
+            (self.reward_weights.wait_time * num_people_waiting as f64) -
+            (self.reward_weights.energy * energy_spent);
+        let done: bool = self.building.time_step >= self.max_steps || violation.is_some();
+
+        //Return the observation, reward, done flag, and step info
+        let info: StepInfo = StepInfo {
+            time_step: self.building.time_step,
+            num_people_waiting: num_people_waiting,
+            energy_spent: energy_spent,
+            tips: tips,
+            commands_rejected: commands_rejected,
+            violation: violation
+        };
+        (self.get_observation(), reward, done, info)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,354 @@
+//Import standard/imported modules
+use std::fmt::Write as FmtWrite;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+//Import source modules
+use crate::building::Building;
+use crate::elevator::{Elevator, LoadLevel};
+use crate::people::People;
+use crate::replay::Replay;
+
+//Constants defining the layout of the exported image, in pixels
+const EXPORT_MARGIN: f64 = 10.0_f64;
+const EXPORT_LABEL_WIDTH: f64 = 40.0_f64;
+const EXPORT_FLOOR_HEIGHT: f64 = 24.0_f64;
+const EXPORT_SHAFT_WIDTH: f64 = 32.0_f64;
+const EXPORT_QUEUE_WIDTH: f64 = 200.0_f64;
+const EXPORT_TIMELINE_HEIGHT: f64 = 120.0_f64;
+const EXPORT_LEGEND_LINE_HEIGHT: f64 = 14.0_f64;
+
+//Constants defining the colors of the exported image
+const EXPORT_COLOR_EMPTY: &str = "#2e7d32";
+const EXPORT_COLOR_PARTIAL: &str = "#f9a825";
+const EXPORT_COLOR_FULL: &str = "#c62828";
+const EXPORT_COLOR_UNAVAILABLE: &str = "#8e24aa";
+const EXPORT_COLOR_QUEUE: &str = "#1565c0";
+const EXPORT_COLOR_WAIT_TIME: &str = "#1565c0";
+const EXPORT_COLOR_WAITING: &str = "#ef6c00";
+
+/// # `ExportFrame` struct
+///
+/// An `ExportFrame` holds the values of a single time step drawn by an `SvgExporter`
+struct ExportFrame {
+    time_step: usize,
+    num_waiting_per_floor: Vec<usize>,
+    car_floors: Vec<usize>,
+    car_colors: Vec<&'static str>,
+    avg_wait_time: f64,
+    num_waiting: usize
+}
+
+/// # `SvgExporter` struct
+///
+/// An `SvgExporter` samples a `Building` each time step and exports the run as a
+/// self-contained animated SVG, or an HTML page embedding it, for sharing in reports.
+/// The image shows the elevator shafts with the cars moving between floors, colored
+/// by how full they are, the queue of people waiting on each floor, and a timeline of
+/// the average wait time and the number of people waiting, with a cursor tracking the
+/// current time step.  The animation uses SVG's declarative SMIL animation, so the
+/// files need no scripts or network access to play in a browser.
+///
+/// The queue bars are scaled so that `max_queue` people fill the queue area, and each
+/// time step is shown for `seconds_per_step` seconds before the animation loops.
+pub struct SvgExporter {
+    pub seconds_per_step: f64,
+    pub max_queue: usize,
+    floor_names: Vec<String>,
+    num_floors: usize,
+    num_elevators: usize,
+    frames: Vec<ExportFrame>
+}
+
+//Implement the SvgExporter interface
+impl SvgExporter {
+    /// Initialize a new SvgExporter given the building it will sample and the number
+    /// of seconds each time step is shown for.  The number of floors and elevators
+    /// drawn is fixed by the building, so the exporter should only sample that
+    /// building.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let my_building: Building = Building::from(
+    ///     4_usize,
+    ///     2_usize,
+    ///     0.5_f64,
+    ///     5.0_f64,
+    ///     2.5_f64,
+    ///     0.5_f64
+    /// );
+    /// let my_exporter: SvgExporter = SvgExporter::from(&my_building, 0.25_f64);
+    /// ```
+    pub fn from(building: &Building, seconds_per_step: f64) -> SvgExporter {
+        SvgExporter {
+            seconds_per_step: seconds_per_step,
+            max_queue: 20_usize,
+            floor_names: building.floors.iter().map(|floor| floor.name.clone()).collect(),
+            num_floors: building.floors.len(),
+            num_elevators: building.elevators.len(),
+            frames: Vec::new()
+        }
+    }
+
+    /// Initialize a new SvgExporter holding every time step of a `Replay`, from its
+    /// first step to its last.  Returns an error if the replay diverges from its
+    /// recorded run.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let my_log: ReplayLog = ReplayLog::load(Path::new("failing_run.json")).unwrap();
+    /// let mut my_replay: Replay = Replay::from(my_log).unwrap();
+    /// let my_exporter: SvgExporter = SvgExporter::from_replay(&mut my_replay, 0.25_f64).unwrap();
+    /// my_exporter.save(Path::new("failing_run.html")).unwrap();
+    /// ```
+    pub fn from_replay(replay: &mut Replay, seconds_per_step: f64) -> Result<SvgExporter, String> {
+        replay.seek(0_usize)?;
+        let mut exporter: SvgExporter = SvgExporter::from(&replay.building, seconds_per_step);
+        exporter.sample(&replay.building);
+        while replay.step_forward()? {
+            exporter.sample(&replay.building);
+        }
+        Ok(exporter)
+    }
+
+    /// Get the number of time steps sampled
+    pub fn get_num_frames(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Sample the building's state as a new frame.  Generally called once after each
+    /// time step.
+    pub fn sample(&mut self, building: &Building) {
+        //Get the color of each car by how full it is, or if it is out of service
+        let car_colors: Vec<&'static str> = building.elevators.iter()
+            .map(SvgExporter::get_load_color)
+            .collect();
+
+        //Append the frame
+        let num_waiting_per_floor: Vec<usize> = building.floors.iter()
+            .map(|floor| floor.get_num_people_waiting())
+            .collect();
+        self.frames.push(ExportFrame {
+            time_step: building.time_step,
+            num_waiting: num_waiting_per_floor.iter().sum(),
+            num_waiting_per_floor: num_waiting_per_floor,
+            car_floors: building.elevators.iter().map(|elevator| elevator.floor_on).collect(),
+            car_colors: car_colors,
+            avg_wait_time: building.avg_wait_time
+        });
+    }
+
+    /// Get the color of an elevator given how full it is, or purple if it is out of
+    /// service
+    fn get_load_color(elevator: &Elevator) -> &'static str {
+        match elevator.get_load_level() {
+            LoadLevel::Light => EXPORT_COLOR_EMPTY,
+            LoadLevel::Partial => EXPORT_COLOR_PARTIAL,
+            LoadLevel::Full => EXPORT_COLOR_FULL,
+            LoadLevel::Unavailable => EXPORT_COLOR_UNAVAILABLE
+        }
+    }
+
+    /// Get the y coordinate of the top of a floor
+    fn get_floor_y(&self, floor_index: usize) -> f64 {
+        EXPORT_MARGIN + (self.num_floors - floor_index - 1_usize) as f64 * EXPORT_FLOOR_HEIGHT
+    }
+
+    /// Get a discrete SMIL animation of an attribute, taking each of the values in
+    /// turn for one time step
+    fn get_animation(&self, attribute: &str, values: &[String]) -> String {
+        format!(
+            "<animate attributeName=\"{}\" values=\"{}\" dur=\"{:.3}s\" calcMode=\"discrete\" repeatCount=\"indefinite\"/>",
+            attribute,
+            values.join(";"),
+            self.frames.len() as f64 * self.seconds_per_step
+        )
+    }
+
+    /// Get the points of a timeline polyline given its values, scaled so that the
+    /// largest value reaches the top of the timeline
+    fn get_timeline_points(values: &[f64], left: f64, top: f64, width: f64) -> String {
+        let max_value: f64 = values.iter().cloned().fold(0.0_f64, f64::max).max(f64::EPSILON);
+        let x_step: f64 = width / (values.len().max(2_usize) - 1_usize) as f64;
+        values.iter()
+            .enumerate()
+            .map(|(i, value)| format!(
+                "{:.1},{:.1}",
+                left + i as f64 * x_step,
+                top + EXPORT_TIMELINE_HEIGHT * (1.0_f64 - value / max_value)
+            ))
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    /// Export the sampled frames as a self-contained animated SVG image
+    pub fn to_svg(&self) -> String {
+        //Calculate the dimensions of the image
+        let shafts_left: f64 = EXPORT_MARGIN + EXPORT_LABEL_WIDTH;
+        let queues_left: f64 = shafts_left + self.num_elevators as f64 * EXPORT_SHAFT_WIDTH + EXPORT_MARGIN;
+        let width: f64 = queues_left + EXPORT_QUEUE_WIDTH + EXPORT_MARGIN;
+        let timeline_top: f64 = EXPORT_MARGIN * 4.0_f64 + self.num_floors as f64 * EXPORT_FLOOR_HEIGHT;
+        let timeline_width: f64 = width - shafts_left - EXPORT_MARGIN;
+        let height: f64 = timeline_top + EXPORT_TIMELINE_HEIGHT + EXPORT_LEGEND_LINE_HEIGHT * 3.0_f64 + EXPORT_MARGIN * 2.0_f64;
+        let mut svg: String = String::new();
+        let _ = writeln!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{:.0}\" height=\"{:.0}\" viewBox=\"0 0 {:.0} {:.0}\" font-family=\"monospace\" font-size=\"11\">",
+            width, height, width, height
+        );
+        let _ = writeln!(svg, "<rect width=\"100%\" height=\"100%\" fill=\"#ffffff\"/>");
+
+        //Draw each floor with its label and the outline of each shaft
+        for floor_index in 0..self.num_floors {
+            let y: f64 = self.get_floor_y(floor_index);
+            let _ = writeln!(
+                svg,
+                "<text x=\"{:.1}\" y=\"{:.1}\" dominant-baseline=\"middle\">{}</text>",
+                EXPORT_MARGIN, y + EXPORT_FLOOR_HEIGHT / 2.0_f64, escape_markup(&self.floor_names[floor_index])
+            );
+            let _ = writeln!(
+                svg,
+                "<line x1=\"{:.1}\" y1=\"{:.1}\" x2=\"{:.1}\" y2=\"{:.1}\" stroke=\"#bdbdbd\"/>",
+                shafts_left, y + EXPORT_FLOOR_HEIGHT, width - EXPORT_MARGIN, y + EXPORT_FLOOR_HEIGHT
+            );
+        }
+        for elevator_index in 0..self.num_elevators {
+            let _ = writeln!(
+                svg,
+                "<rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\" fill=\"none\" stroke=\"#757575\"/>",
+                shafts_left + elevator_index as f64 * EXPORT_SHAFT_WIDTH,
+                EXPORT_MARGIN,
+                EXPORT_SHAFT_WIDTH,
+                self.num_floors as f64 * EXPORT_FLOOR_HEIGHT
+            );
+        }
+
+        //If there are no frames, then there is nothing to animate
+        if self.frames.is_empty() {
+            svg.push_str("</svg>\n");
+            return svg;
+        }
+
+        //Draw each car, moving between floors and changing color with its load
+        for elevator_index in 0..self.num_elevators {
+            let ys: Vec<String> = self.frames.iter()
+                .map(|frame| format!("{:.1}", self.get_floor_y(frame.car_floors[elevator_index]) + 2.0_f64))
+                .collect();
+            let colors: Vec<String> = self.frames.iter()
+                .map(|frame| String::from(frame.car_colors[elevator_index]))
+                .collect();
+            let _ = writeln!(
+                svg,
+                "<rect x=\"{:.1}\" y=\"{}\" width=\"{:.1}\" height=\"{:.1}\" rx=\"3\" fill=\"{}\">{}{}</rect>",
+                shafts_left + elevator_index as f64 * EXPORT_SHAFT_WIDTH + 3.0_f64,
+                ys[0],
+                EXPORT_SHAFT_WIDTH - 6.0_f64,
+                EXPORT_FLOOR_HEIGHT - 4.0_f64,
+                colors[0],
+                self.get_animation("y", &ys),
+                self.get_animation("fill", &colors)
+            );
+        }
+
+        //Draw the queue on each floor, growing with the number of people waiting
+        let person_width: f64 = EXPORT_QUEUE_WIDTH / self.max_queue.max(1_usize) as f64;
+        for floor_index in 0..self.num_floors {
+            let widths: Vec<String> = self.frames.iter()
+                .map(|frame| format!("{:.1}", frame.num_waiting_per_floor[floor_index].min(self.max_queue) as f64 * person_width))
+                .collect();
+            let _ = writeln!(
+                svg,
+                "<rect x=\"{:.1}\" y=\"{:.1}\" width=\"{}\" height=\"{:.1}\" fill=\"{}\">{}</rect>",
+                queues_left,
+                self.get_floor_y(floor_index) + 6.0_f64,
+                widths[0],
+                EXPORT_FLOOR_HEIGHT - 12.0_f64,
+                EXPORT_COLOR_QUEUE,
+                self.get_animation("width", &widths)
+            );
+        }
+
+        //Draw the timeline of the metrics with its legend
+        let wait_times: Vec<f64> = self.frames.iter().map(|frame| frame.avg_wait_time).collect();
+        let num_waiting: Vec<f64> = self.frames.iter().map(|frame| frame.num_waiting as f64).collect();
+        let _ = writeln!(
+            svg,
+            "<rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\" fill=\"#fafafa\" stroke=\"#bdbdbd\"/>",
+            shafts_left, timeline_top, timeline_width, EXPORT_TIMELINE_HEIGHT
+        );
+        for (values, color) in [(&wait_times, EXPORT_COLOR_WAIT_TIME), (&num_waiting, EXPORT_COLOR_WAITING)] {
+            let _ = writeln!(
+                svg,
+                "<polyline points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"1.5\"/>",
+                SvgExporter::get_timeline_points(values, shafts_left, timeline_top, timeline_width),
+                color
+            );
+        }
+        let legend_lines: [(String, &str); 3] = [
+            (format!("avg wait time (max {:.2})", wait_times.iter().cloned().fold(0.0_f64, f64::max)), EXPORT_COLOR_WAIT_TIME),
+            (format!("people waiting (max {})", self.frames.iter().map(|frame| frame.num_waiting).max().unwrap_or(0_usize)), EXPORT_COLOR_WAITING),
+            (format!("time steps {}-{}", self.frames[0].time_step, self.frames[self.frames.len() - 1_usize].time_step), "#424242")
+        ];
+        for (i, (line, color)) in legend_lines.iter().enumerate() {
+            let _ = writeln!(
+                svg,
+                "<text x=\"{:.1}\" y=\"{:.1}\" fill=\"{}\">{}</text>",
+                shafts_left,
+                timeline_top + EXPORT_TIMELINE_HEIGHT + EXPORT_MARGIN + (i + 1_usize) as f64 * EXPORT_LEGEND_LINE_HEIGHT,
+                color,
+                line
+            );
+        }
+
+        //Draw the cursor tracking the current time step along the timeline
+        let x_step: f64 = timeline_width / (self.frames.len().max(2_usize) - 1_usize) as f64;
+        let xs: Vec<String> = (0..self.frames.len())
+            .map(|i| format!("{:.1}", shafts_left + i as f64 * x_step - 1.0_f64))
+            .collect();
+        let _ = writeln!(
+            svg,
+            "<rect x=\"{}\" y=\"{:.1}\" width=\"2\" height=\"{:.1}\" fill=\"#424242\">{}</rect>",
+            xs[0], timeline_top, EXPORT_TIMELINE_HEIGHT, self.get_animation("x", &xs)
+        );
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Export the sampled frames as a self-contained HTML page embedding the animated
+    /// SVG image, given the title of the page
+    pub fn to_html(&self, title: &str) -> String {
+        let title: String = escape_markup(title);
+        format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n</head>\n<body>\n<h1 style=\"font-family: sans-serif\">{}</h1>\n{}</body>\n</html>\n",
+            title, title, self.to_svg()
+        )
+    }
+
+    /// Save the sampled frames to a file, as an HTML page if the path ends in `.html`
+    /// or `.htm`, and as an SVG image otherwise
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let is_html: bool = path.extension()
+            .map(|extension| extension == "html" || extension == "htm")
+            .unwrap_or(false);
+        if is_html {
+            let title: String = path.file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_else(|| String::from("Elevator simulation"));
+            fs::write(path, self.to_html(&title))
+        } else {
+            fs::write(path, self.to_svg())
+        }
+    }
+}
+
+/// Escape the given text so that it can be written as the content or an attribute
+/// value of an SVG or HTML element
+pub fn escape_markup(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
\ No newline at end of file
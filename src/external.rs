@@ -0,0 +1,207 @@
+//Import standard/imported modules
+use std::io::{self, BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+use serde::Serialize;
+use serde_json::Value;
+
+//Import source modules
+use crate::controller::ElevatorController;
+use crate::view::BuildingView;
+use crate::command::ElevatorCommand;
+
+/// # `ExternalController` struct
+///
+/// An `ExternalController` implements the `ElevatorController` trait.  It launches a
+/// child process and delegates its decisions to it over a line-based JSON protocol,
+/// so that controllers can be prototyped in any language.  Each time step, a request
+/// of the form `{"time_step": ..., "view": ...}` holding the time step and the
+/// `BuildingView` is written to the process's stdin as a single line of JSON, and the
+/// process is expected to write back a single line of the form
+/// `{"time_step": ..., "targets": [...]}`, echoing the time step along with the
+/// target floor of each elevator.  Targets which are `null`, are not integers, or are
+/// beyond the building hold the elevator stopped on its current floor.
+///
+/// If the process does not respond within the timeout, responds with something other
+/// than a JSON object of that form, or exits, then the error is stored in
+/// `last_error` and every elevator is held on its current floor for that time step.
+/// Responses echoing a different time step are late responses to earlier requests,
+/// and are discarded while waiting.
+pub struct ExternalController {
+    pub timeout: Duration,
+    pub last_error: Option<String>,
+    child: Child,
+    stdin: ChildStdin,
+    responses: Receiver<String>
+}
+
+/// # `ControllerRequest` struct
+///
+/// A `ControllerRequest` is the request written to an `ExternalController`'s child
+/// process each time step.
+#[derive(Serialize)]
+struct ControllerRequest<'a> {
+    time_step: usize,
+    view: &'a BuildingView
+}
+
+//Implement the ExternalController interface
+impl ExternalController {
+    /// Initialize a new ExternalController given the program to launch along with its
+    /// arguments, and the time to wait for each response.  Returns an error if the
+    /// process cannot be launched.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let my_controller: ExternalController = ExternalController::from(
+    ///     "python3",
+    ///     &["scripts/nearest_controller.py"],
+    ///     Duration::from_millis(500_u64)
+    /// ).unwrap();
+    /// ```
+    pub fn from(program: &str, args: &[&str], timeout: Duration) -> io::Result<ExternalController> {
+        //Launch the child process with piped stdin and stdout
+        let mut child: Child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()?;
+        let stdin: ChildStdin = child.stdin.take()
+            .ok_or_else(|| io::Error::other("cannot open child stdin"))?;
+        let stdout = child.stdout.take()
+            .ok_or_else(|| io::Error::other("cannot open child stdout"))?;
+
+        //Read the child's responses on a separate thread so that reads can time out
+        let (sender, responses) = mpsc::channel::<String>();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines() {
+                match line {
+                    Ok(line) => if sender.send(line).is_err() { break; },
+                    Err(_) => break
+                }
+            }
+        });
+
+        //Initialize the controller
+        Ok(ExternalController {
+            timeout: timeout,
+            last_error: None,
+            child: child,
+            stdin: stdin,
+            responses: responses
+        })
+    }
+
+    /// Send the view to the child process and wait for its response to it, returning
+    /// the target floor of each elevator or a description of the error.
+    fn request_targets(&mut self, view: &BuildingView) -> Result<Vec<Option<usize>>, String> {
+        //Discard any late responses to previous requests
+        while self.responses.try_recv().is_ok() {}
+
+        //Write the request as a single line of JSON
+        let request: String = serde_json::to_string(&ControllerRequest { time_step: view.time_step, view: view })
+            .map_err(|err| format!("cannot encode view: {}", err))?;
+        writeln!(self.stdin, "{}", request)
+            .and_then(|_| self.stdin.flush())
+            .map_err(|err| format!("cannot write to controller process: {}", err))?;
+
+        //Wait for the response to this time step, discarding late responses
+        let deadline: Instant = Instant::now() + self.timeout;
+        loop {
+            let response: String = match self.responses.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                Ok(response) => response,
+                Err(RecvTimeoutError::Timeout) => {
+                    return Err(format!("controller process timed out after {:?}", self.timeout));
+                },
+                Err(RecvTimeoutError::Disconnected) => {
+                    return Err(String::from("controller process exited"));
+                }
+            };
+
+            //Parse the response as an object holding the time step and target floors
+            let response: Value = serde_json::from_str::<Value>(&response)
+                .map_err(|err| format!("invalid JSON from controller process: {}", err))?;
+            let (time_step, targets): (u64, &Vec<Value>) = match (
+                response.get("time_step").and_then(Value::as_u64),
+                response.get("targets").and_then(Value::as_array)
+            ) {
+                (Some(time_step), Some(targets)) => (time_step, targets),
+                _ => return Err(format!("expected a time step and an array of target floors, got {}", response))
+            };
+
+            //Skip the response if it is for an earlier time step
+            if time_step != view.time_step as u64 {
+                continue;
+            }
+            return Ok(targets.iter()
+                .map(|target| target.as_u64().map(|target| target as usize))
+                .collect());
+        }
+    }
+}
+
+//Implement the ElevatorController trait for the ExternalController
+impl ElevatorController for ExternalController {
+    /// Return a boolean signifying whether the controller can be upgraded or
+    /// not.  Always returns false, since the ExternalController cannot be
+    /// upgraded.
+    fn can_be_upgraded(&self) -> bool {
+        false
+    }
+
+    /// Upgrade the controller given an incrementation float.  Does nothing for
+    /// the ExternalController since it cannot be upgraded.
+    fn upgrade(&mut self, _incrementation: f64) {}
+
+    /// Request the target floors from the child process, and command each available
+    /// elevator toward the target floor returned for it.  If the request fails, then
+    /// the error is stored in `last_error` and every elevator is held on its current
+    /// floor.
+    fn decide(&mut self, view: &BuildingView) -> Vec<ElevatorCommand> {
+        //Request the target floors from the child process
+        let targets: Vec<Option<usize>> = match self.request_targets(view) {
+            Ok(targets) => {
+                self.last_error = None;
+                targets
+            },
+            Err(err) => {
+                self.last_error = Some(err);
+                return Vec::new();
+            }
+        };
+
+        //Loop through the elevators and command them toward their target floors
+        let mut commands: Vec<ElevatorCommand> = Vec::new();
+        for (i, elevator) in view.elevators.iter().enumerate() {
+            //Skip elevators which are unavailable
+            if !elevator.is_available() {
+                continue;
+            }
+
+            //Get the elevator's target floor, holding it if the target is invalid
+            let target: Option<usize> = targets.get(i)
+                .copied()
+                .flatten()
+                .filter(|target| *target < view.get_num_floors());
+            commands.push(match target {
+                Some(floor_index) => ElevatorCommand::GoToFloor { elevator_index: i, floor_index: floor_index },
+                None => ElevatorCommand::Stop { elevator_index: i }
+            });
+        }
+
+        //Return the commands
+        commands
+    }
+}
+
+//Implement the Drop trait for the ExternalController
+impl Drop for ExternalController {
+    /// Kill the child process when the controller is dropped
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
\ No newline at end of file
@@ -1,28 +1,34 @@
 //Import external/standard modules
-use rand::Rng;
+use rand::RngCore;
 
 //Import source modules
 use crate::person::Person;
 use crate::people::People;
+use crate::validation::ViolationKind;
+use crate::outcome::OutcomeSource;
 
 /// # `Floor` struct
 ///
 /// A `Floor` is aggregated by buildings.  People travel between them using
 /// elevators.  The floor struct generally should not be directly instantiated;
-/// instead it should be managed in aggregate via the `Building` type.
+/// instead it should be managed in aggregate via the `Building` type.  The floor's
+/// `name` is the label it is displayed with, such as `"-1"` for a basement level.  The
+/// floor counts the people who decided to leave the building from it.
 #[derive(Clone)]
 pub struct Floor {
     people: Vec<Person>,
     pub capacity: usize,
-    pub dest_prob: f64
+    pub dest_prob: f64,
+    pub name: String,
+    pub tot_leaving: usize
 }
 
 /// # `Floor` type implementation
 ///
 /// The following functions are used by `Building`s and `Floors` implementations.
 impl Floor {
-    /// Initialize a new Floor with a zero destination probability and an empty
-    /// vector of `Person`s.
+    /// Initialize a new Floor with a zero destination probability, an empty name, and
+    /// an empty vector of `Person`s.
     ///
     /// ## Example
     ///
@@ -34,7 +40,9 @@ impl Floor {
         Floor {
             people: Vec::new(),
             capacity: capacity,
-            dest_prob: 0_f64
+            dest_prob: 0_f64,
+            name: String::new(),
+            tot_leaving: 0_usize
         }
     }
 
@@ -43,48 +51,41 @@ impl Floor {
         self.capacity - self.people.get_num_people()
     }
 
-    /// Calculate the probability that a person on the floor leaves during the next
-    /// time step, and return the result as an f64.
+    /// Calculate the probability that anyone on the floor who is not waiting for the
+    /// elevator leaves during the next time step, and return the result as an f64.
+    /// Takes linear time in the number of people on the floor.
     pub fn get_p_out(&self) -> f64 {
-        //If there is no one on the floor, return 0_f64
-        if self.people.len() == 0 {
-            return 0_f64;
-        }
+        self.get_p_hall_call(1_f64)
+    }
 
-        //Initialize a p_out variable and a vec for each p_out
-        let mut p_out: f64 = 0_f64;
-        let mut past_p_outs: Vec<f64> = Vec::new();
-
-        //Loop through the people in the floor and iteratively calculate
-        //the p_out value
-        for pers in self.people.iter() {
-            //Calculate the product of each of the past people's inverse
-            //p_out values
-            let inverse_p_outs: f64 = {
-                let mut tmp_inverse_p_outs: f64 = 1_f64;
-                for past_p_out in &past_p_outs {
-                    tmp_inverse_p_outs = tmp_inverse_p_outs * (1_f64 - past_p_out);
-                }
-                tmp_inverse_p_outs
-            };
-
-            //Calculate the summand value based on the person's p_out and
-            //the product of each of the past people's p_out values
-            let tmp_p_out: f64 = pers.p_out * inverse_p_outs;
-
-            //Add the newly calculated value onto the p_out value and then
-            //append the current p_out
-            p_out += tmp_p_out;
-            past_p_outs.push(pers.p_out);
-        }
+    /// Calculate the probability that anyone on the floor who is not waiting for the
+    /// elevator leaves and calls the elevator during the next time step, given the
+    /// probability that someone leaving exits the building from another floor, and
+    /// return the result as an f64.  Takes linear time in the number of people on the
+    /// floor.
+    pub fn get_p_hall_call(&self, p_exit_elsewhere: f64) -> f64 {
+        //Multiply the probabilities that each person who is not waiting stays
+        let p_no_call: f64 = self.people.iter()
+            .filter(|pers| !pers.is_waiting())
+            .fold(1_f64, |p_no_call, pers| p_no_call * (1_f64 - pers.p_out * p_exit_elsewhere));
+
+        //Return the probability that anyone does not stay
+        1_f64 - p_no_call
+    }
 
-        //Return the p_out value
-        p_out
+    /// Calculate the expected number of people on the floor who are not waiting for
+    /// the elevator and leave during the next time step
+    pub fn get_expected_num_leaving(&self) -> f64 {
+        self.people.iter()
+            .filter(|pers| !pers.is_waiting())
+            .map(|pers| pers.p_out)
+            .sum()
     }
 
     /// Randomly generate whether anyone on the floor is leaving using each `Person`'s
-    /// `gen_is_leaving` function.
-    pub fn gen_people_leaving(&mut self, rng: &mut impl Rng) {
+    /// `gen_is_leaving` function, and count the people who decided to leave.  The
+    /// decisions are made by the given `OutcomeSource`.
+    pub fn gen_people_leaving(&mut self, outcomes: &mut dyn OutcomeSource) {
         //Loop through the people on the floor and decide if they are leaving
         for pers in self.people.iter_mut() {
             //Skip people who are waiting for the elevator
@@ -93,19 +94,30 @@ impl Floor {
             }
 
             //Randomly generate whether someone not waiting for the elevator will leave
-            let _is_person_leaving: bool = pers.gen_is_leaving(rng);
+            let was_person_leaving: bool = pers.is_leaving;
+            let is_person_leaving: bool = pers.gen_is_leaving(outcomes);
+            if is_person_leaving && !was_person_leaving {
+                self.tot_leaving += 1_usize;
+            }
         }
     }
 
     /// Remove people from a floor who are currently waiting/not on their desired floor
     /// and return as a `Vec<Person>`.  This is used when the elevator is on this floor
     /// and there is an exchange of people between the elevator and the floor.  The people
-    /// removed from the floor are limited to the free capacity of the elevator they are
-    /// entering, which is given as a usize function parameter.
-    pub fn flush_people_entering_elevator(&mut self, free_elevator_capacity: usize) -> Vec<Person> {
+    /// removed from the floor are limited to the free capacity, free load (in kg), and
+    /// free volume (in cubic meters) of the elevator they are entering, which are given
+    /// as function parameters.  Anyone who would exceed the free load or volume is
+    /// skipped, and keeps waiting on the floor.
+    pub fn flush_people_entering_elevator(&mut self, free_elevator_capacity: usize,
+                                          free_elevator_load: f64, free_elevator_volume: f64) -> Vec<Person> {
         //Initialize a vector of people for the people entering the elevator
         let mut people_entering_elevator: Vec<Person> = Vec::new();
 
+        //Initialize the remaining load and volume the elevator can take on
+        let mut free_load: f64 = free_elevator_load;
+        let mut free_volume: f64 = free_elevator_volume;
+
         //Loop through the people on the floor and add to the vec
         let mut removals = 0_usize;
         for i in 0..self.people.len() {
@@ -120,9 +132,16 @@ impl Floor {
                 continue;
             }
 
+            //If the person does not fit in the remaining load or volume, then skip
+            if self.people[i-removals].mass > free_load || self.people[i-removals].volume > free_volume {
+                continue;
+            }
+
             //If the person is waiting, then remove them from the elevator
             //and add them to the leaving vec, incrementing the removals
             let person_entering_elevator: Person = self.people.remove(i - removals);
+            free_load -= person_entering_elevator.mass;
+            free_volume -= person_entering_elevator.volume;
             people_entering_elevator.push(person_entering_elevator);
             removals += 1_usize;
         }
@@ -131,8 +150,90 @@ impl Floor {
         people_entering_elevator
     }
 
-    /// Remove people entirely who are leaving the building.  This is used exclusively
-    /// on the first floor.
+    /// Check the structural invariants of the floor given its index in the building and
+    /// the number of floors in the building.  Returns the first violation found, if any.
+    pub fn validate(&self, floor_index: usize, num_floors: usize) -> Result<(), ViolationKind> {
+        //Check that the floor is within its capacity
+        if self.people.len() > self.capacity {
+            return Err(ViolationKind::FloorOverCapacity {
+                floor_index: floor_index,
+                num_people: self.people.len(),
+                capacity: self.capacity
+            });
+        }
+
+        //Check that each person is on this floor and going to a real floor
+        for (i, pers) in self.people.iter().enumerate() {
+            if pers.floor_on != floor_index {
+                return Err(ViolationKind::FloorPersonMismatch {
+                    floor_index: floor_index,
+                    person_index: i,
+                    person_floor_on: pers.floor_on
+                });
+            }
+            if pers.floor_to >= num_floors {
+                return Err(ViolationKind::DestinationOutOfBounds {
+                    floor_index: floor_index,
+                    person_index: i,
+                    floor_to: pers.floor_to,
+                    num_floors: num_floors
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Add people onto the floor until at capacity, and return anyone who did not fit
+    /// as a `Vec<Person>`.
+    pub fn try_extend<T: IntoIterator<Item=Person>>(&mut self, iter: T) -> Vec<Person> {
+        //Initialize a vector of people for the people rejected by the floor
+        let mut people_rejected: Vec<Person> = Vec::new();
+
+        //Add people onto the floor until at capacity
+        for pers in iter {
+            //Reject the person if we reach capacity
+            if self.people.get_num_people() >= self.capacity {
+                people_rejected.push(pers);
+                continue;
+            }
+
+            //Add a person
+            self.people.push(pers);
+        }
+
+        //Return the vector of people rejected
+        people_rejected
+    }
+
+    /// Remove people from a floor who are waiting but give up on the elevator, either
+    /// because they renege after waiting longer than their patience, or because their
+    /// trip is short enough to take the stairs.  Returns the people removed as a
+    /// `Vec<Person>` so that they can walk to their desired floor.
+    pub fn flush_people_abandoning(&mut self) -> Vec<Person> {
+        //Initialize a vector of people for the people abandoning the elevator
+        let mut people_abandoning: Vec<Person> = Vec::new();
+
+        //Loop through the people on the floor and add to the vec if abandoning
+        let mut removals = 0_usize;
+        for i in 0..self.people.len() {
+            //If the person is neither reneging nor taking the stairs, then skip
+            if !self.people[i-removals].is_reneging() && !self.people[i-removals].is_taking_stairs() {
+                continue;
+            }
+
+            //If the person is abandoning, then remove them from the floor
+            //and add them to the abandoning vec, incrementing the removals
+            let person_abandoning: Person = self.people.remove(i - removals);
+            people_abandoning.push(person_abandoning);
+            removals += 1_usize;
+        }
+
+        //Return the vector of people abandoning
+        people_abandoning
+    }
+
+    /// Remove people entirely who are leaving the building and have reached their exit
+    /// floor.  This is used exclusively on the building's entrance floors.
     pub fn flush_people_leaving_floor(&mut self) -> Vec<Person> {
         //Initialize a vector of people for the people leaving the floor
         let mut people_leaving_floor: Vec<Person> = Vec::new();
@@ -140,8 +241,8 @@ impl Floor {
         //Loop through the people on the floor and add to the vec if leaving
         let mut removals = 0_usize;
         for i in 0..self.people.len() {
-            //If the person is not leaving, then skip
-            if !self.people[i-removals].is_leaving {
+            //If the person is not leaving, or is leaving from another floor, then skip
+            if !self.people[i-removals].is_leaving || self.people[i-removals].is_waiting() {
                 continue;
             }
 
@@ -159,24 +260,19 @@ impl Floor {
 
 //Implement the extend trait for the floor struct
 impl Extend<Person> for Floor {
+    /// Add people onto the floor, who are expected to fit within its capacity.  Panics
+    /// in debug builds if anyone does not fit, since they would otherwise vanish; use
+    /// `try_extend` to get them back instead.
     fn extend<T: IntoIterator<Item=Person>>(&mut self, iter: T) {
-        //Add people onto the floor until at capacity
-        for pers in iter {
-            //Break if we reach capacity
-            if self.people.get_num_people() == self.capacity {
-                break;
-            }
-
-            //Add a person
-            self.people.push(pers);
-        }
+        let people_rejected: Vec<Person> = self.try_extend(iter);
+        debug_assert!(people_rejected.is_empty(), "{} people did not fit on the floor", people_rejected.len());
     }
 }
 
 //Implement the people trait for the floor struct
 impl People for Floor {
     /// Generates the number of people among the collection of people who will tip.
-    fn gen_num_tips(&self, rng: &mut impl Rng) -> usize {
+    fn gen_num_tips(&self, rng: &mut dyn RngCore) -> usize {
         self.people.gen_num_tips(rng)
     }
 
@@ -208,6 +304,17 @@ impl People for Floor {
         self.people.get_aggregate_wait_time()
     }
 
+    /// Aggregates the total mass (in kg) of the people on the floor and returns it as an f64.
+    fn get_aggregate_mass(&self) -> f64 {
+        self.people.get_aggregate_mass()
+    }
+
+    /// Aggregates the total volume (in cubic meters) of the luggage and carts carried
+    /// by the people on the floor and returns it as an f64.
+    fn get_aggregate_volume(&self) -> f64 {
+        self.people.get_aggregate_volume()
+    }
+
     /// Determines whether anyone on the floor are going to a given floor, and returns a
     /// bool which is true if so, and false if not.
     fn are_people_going_to_floor(&self, floor_index: usize) -> bool {
@@ -249,4 +356,10 @@ impl People for Floor {
             pers.reset_wait_time();
         }
     }
+
+    /// Evacuates all people on the floor by directing them to the given exit floor and
+    /// marking them as leaving.
+    fn evacuate(&mut self, floor_to: usize) {
+        self.people.evacuate(floor_to)
+    }
 }
\ No newline at end of file
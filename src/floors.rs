@@ -2,9 +2,7 @@
 use crate::floor::Floor;
 use crate::person::Person;
 use crate::people::People;
-
-//Import external/standard modules
-use rand::Rng;
+use crate::outcome::OutcomeSource;
 
 /// # `Floors` trait
 ///
@@ -26,11 +24,12 @@ pub trait Floors {
 
     /// Expected to randomly generate the people leaving each floor using each `Floor`'s
     /// `gen_people_leaving` function, which itself uses each `Person`'s `gen_is_leaving`
-    /// function.
-    fn gen_people_leaving(&mut self, rng: &mut impl Rng);
+    /// function, as decided by the given `OutcomeSource`.
+    fn gen_people_leaving(&mut self, outcomes: &mut dyn OutcomeSource);
 
-    /// Expected to remove anyone who is leaving the first floor.
-    fn flush_first_floor(&mut self) -> Vec<Person>;
+    /// Expected to remove anyone who is leaving the building from the given exit
+    /// floors and return the people who left as a vec of people.
+    fn flush_exit_floors(&mut self, exit_floors: &[usize]) -> Vec<Person>;
 
     /// Expected to increment the waiting times among people who are waiting/not at their
     /// destination floor throughout the collection of floors.
@@ -41,6 +40,13 @@ pub trait Floors {
 
     /// Expected to update the capacities across each of the floors.
     fn update_capacities(&mut self, capacity: usize);
+
+    /// Expected to get the number of people on each floor.
+    fn get_num_people_per_floor(&self) -> Vec<usize>;
+
+    /// Expected to evacuate the people on each floor by directing them to the given
+    /// exit floor and marking them as leaving.
+    fn evacuate(&mut self, floor_to: usize);
 }
 
 //Implement people trait for Vec<Floor>
@@ -109,18 +115,22 @@ impl Floors for Vec<Floor> {
     /// Randomly generates the people leaving each floor using each `Floor`'s
     /// `gen_people_leaving` function, which itself uses each `Person`'s `gen_is_leaving`
     /// function.
-    fn gen_people_leaving(&mut self, mut rng: &mut impl Rng) {
+    fn gen_people_leaving(&mut self, outcomes: &mut dyn OutcomeSource) {
         //Loop through the floors of the building
         for floor in self.iter_mut() {
             //Generate the people leaving on that floor
-            floor.gen_people_leaving(&mut rng);
+            floor.gen_people_leaving(outcomes);
         }
     }
 
-    /// Removes anyone who is leaving the first floor and returns the people who left as
-    /// a vec of people.
-    fn flush_first_floor(&mut self) -> Vec<Person> {
-        self[0].flush_people_leaving_floor()
+    /// Removes anyone who is leaving the building from the given exit floors and returns
+    /// the people who left as a vec of people.
+    fn flush_exit_floors(&mut self, exit_floors: &[usize]) -> Vec<Person> {
+        let mut people_leaving: Vec<Person> = Vec::new();
+        for exit_floor in exit_floors.iter() {
+            people_leaving.extend(self[*exit_floor].flush_people_leaving_floor());
+        }
+        people_leaving
     }
 
     /// Increments the waiting times among people who are waiting/not at their destination
@@ -159,4 +169,26 @@ impl Floors for Vec<Floor> {
             }
         }
     }
+
+    /// Gets the number of people on each floor.
+    fn get_num_people_per_floor(&self) -> Vec<usize> {
+        //Initialize a new vec of usizes
+        let mut num_people_per_floor: Vec<usize> = Vec::new();
+
+        //Loop through the floors and push the number of people on each
+        for floor in self.iter() {
+            num_people_per_floor.push(floor.get_num_people());
+        }
+
+        //Return the vector
+        num_people_per_floor
+    }
+
+    /// Evacuates the people on each floor by directing them to the given exit floor
+    /// and marking them as leaving.
+    fn evacuate(&mut self, floor_to: usize) {
+        for floor in self.iter_mut() {
+            floor.evacuate(floor_to);
+        }
+    }
 }
\ No newline at end of file
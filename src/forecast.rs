@@ -0,0 +1,291 @@
+//Import source modules
+use crate::building::Building;
+
+/// # `ForecastMethod` enum
+///
+/// A `ForecastMethod` decides how a `DemandForecaster` combines the rates it observes
+/// for a time-of-day bucket across periods.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ForecastMethod {
+    /// Each bucket's rates are the average of its observed rates across every period
+    BucketAverage,
+    /// Each bucket's rates are exponentially smoothed across periods given the
+    /// smoothing factor, between 0 and 1, so that recent periods weigh more
+    ExponentialSmoothing { alpha: f64 }
+}
+
+/// # `DemandForecaster` struct
+///
+/// A `DemandForecaster` learns the traffic demand on each floor of a `Building` from
+/// its observed activity, by time of day.  The period, such as a day, is divided into
+/// buckets of equal length, and for each bucket the forecaster learns the rate of
+/// arrivals into the building on each floor and the rate of departures, that is,
+/// people deciding to leave the building from each floor, both per time step.  The
+/// forecaster is trained by observing the building once per time step, which the
+/// building does itself for its own `demand_forecaster`, and each bucket's rates are
+/// updated once the bucket ends.
+#[derive(Clone, Debug)]
+pub struct DemandForecaster {
+    pub period: usize,
+    pub num_buckets: usize,
+    pub method: ForecastMethod,
+    arrival_rates: Vec<Vec<f64>>,
+    departure_rates: Vec<Vec<f64>>,
+    num_periods_observed: Vec<usize>,
+    window_bucket: Option<usize>,
+    window_steps: usize,
+    window_arrivals: Vec<usize>,
+    window_departures: Vec<usize>,
+    prev_arrivals: Vec<usize>,
+    prev_departures: Vec<usize>
+}
+
+//Implement the DemandForecaster interface
+impl DemandForecaster {
+    /// Initialize a new, untrained DemandForecaster given the building it will observe,
+    /// the length of the period in time steps, the number of buckets the period is
+    /// divided into, and the method used to combine the observed rates.  The number of
+    /// floors forecast is fixed by the building, so the forecaster should only observe
+    /// that building.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// //A day of 2400 time steps divided into hour-long buckets
+    /// let my_forecaster: DemandForecaster = DemandForecaster::from(
+    ///     &my_building,
+    ///     2400_usize,
+    ///     24_usize,
+    ///     ForecastMethod::ExponentialSmoothing { alpha: 0.3_f64 }
+    /// );
+    /// ```
+    pub fn from(building: &Building, period: usize, num_buckets: usize, method: ForecastMethod) -> DemandForecaster {
+        //Clamp the period and the number of buckets so every bucket is nonempty
+        let period: usize = period.max(1_usize);
+        let num_buckets: usize = num_buckets.clamp(1_usize, period);
+        let num_floors: usize = building.floors.len();
+
+        //Initialize the forecaster with the building's current counts as the baseline
+        DemandForecaster {
+            period: period,
+            num_buckets: num_buckets,
+            method: method,
+            arrival_rates: vec![vec![0_f64; num_floors]; num_buckets],
+            departure_rates: vec![vec![0_f64; num_floors]; num_buckets],
+            num_periods_observed: vec![0_usize; num_buckets],
+            window_bucket: None,
+            window_steps: 0_usize,
+            window_arrivals: vec![0_usize; num_floors],
+            window_departures: vec![0_usize; num_floors],
+            prev_arrivals: DemandForecaster::get_arrivals_per_floor(building),
+            prev_departures: DemandForecaster::get_departures_per_floor(building)
+        }
+    }
+
+    /// Get the index of the bucket containing the given time step
+    pub fn get_bucket(&self, time_step: usize) -> usize {
+        (time_step % self.period) * self.num_buckets / self.period
+    }
+
+    /// Get the number of periods over which the bucket containing the given time step
+    /// has been observed.  The bucket's rates are zero until it has been observed.
+    pub fn get_num_periods_observed(&self, time_step: usize) -> usize {
+        self.num_periods_observed[self.get_bucket(time_step)]
+    }
+
+    /// Get the forecast number of arrivals into the building on each floor per time
+    /// step, during the given time step
+    pub fn get_arrival_rates(&self, time_step: usize) -> &[f64] {
+        &self.arrival_rates[self.get_bucket(time_step)]
+    }
+
+    /// Get the forecast number of people leaving the building from each floor per
+    /// time step, during the given time step
+    pub fn get_departure_rates(&self, time_step: usize) -> &[f64] {
+        &self.departure_rates[self.get_bucket(time_step)]
+    }
+
+    /// Forecast the expected number of trips starting on each floor, whether arrivals
+    /// or departures, over the given number of time steps starting from the given time
+    /// step.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// //The expected number of trips from each floor over the next 100 time steps
+    /// let demand: Vec<f64> = my_forecaster.forecast_demand(my_building.time_step, 100_usize);
+    /// ```
+    pub fn forecast_demand(&self, time_step: usize, horizon: usize) -> Vec<f64> {
+        let mut demand: Vec<f64> = vec![0_f64; self.window_arrivals.len()];
+        for step in time_step..time_step + horizon {
+            let bucket: usize = self.get_bucket(step);
+            for (i, floor_demand) in demand.iter_mut().enumerate() {
+                *floor_demand += self.arrival_rates[bucket][i] + self.departure_rates[bucket][i];
+            }
+        }
+        demand
+    }
+
+    /// Observe the building's activity during its current time step.  Generally
+    /// called once at the end of each time step, before the time step is incremented.
+    pub fn observe(&mut self, building: &Building) {
+        //Count the arrivals and departures on each floor since the last observation
+        let arrivals: Vec<usize> = DemandForecaster::get_arrivals_per_floor(building);
+        let departures: Vec<usize> = DemandForecaster::get_departures_per_floor(building);
+
+        //If the time step starts a new bucket, then learn from the previous one
+        let bucket: usize = self.get_bucket(building.time_step);
+        if self.window_bucket != Some(bucket) {
+            self.update_rates();
+            self.window_bucket = Some(bucket);
+        }
+
+        //Add the counts onto the bucket's window
+        for i in 0..self.window_arrivals.len() {
+            self.window_arrivals[i] += arrivals[i].saturating_sub(self.prev_arrivals[i]);
+            self.window_departures[i] += departures[i].saturating_sub(self.prev_departures[i]);
+        }
+        self.window_steps += 1_usize;
+        self.prev_arrivals = arrivals;
+        self.prev_departures = departures;
+    }
+
+    /// Update the rates of the bucket whose window was being observed from the counts
+    /// in the window, then clear the window
+    fn update_rates(&mut self) {
+        //If no window was being observed, then there is nothing to learn
+        let bucket: usize = match self.window_bucket {
+            Some(bucket) if self.window_steps > 0_usize => bucket,
+            _ => return
+        };
+
+        //Get the weight of the window's rates according to the method
+        self.num_periods_observed[bucket] += 1_usize;
+        let weight: f64 = match self.method {
+            _ if self.num_periods_observed[bucket] == 1_usize => 1_f64,
+            ForecastMethod::BucketAverage => 1_f64 / self.num_periods_observed[bucket] as f64,
+            ForecastMethod::ExponentialSmoothing { alpha } => alpha
+        };
+
+        //Move the bucket's rates toward the window's rates
+        for i in 0..self.window_arrivals.len() {
+            let arrival_rate: f64 = self.window_arrivals[i] as f64 / self.window_steps as f64;
+            let departure_rate: f64 = self.window_departures[i] as f64 / self.window_steps as f64;
+            self.arrival_rates[bucket][i] += weight * (arrival_rate - self.arrival_rates[bucket][i]);
+            self.departure_rates[bucket][i] += weight * (departure_rate - self.departure_rates[bucket][i]);
+        }
+
+        //Clear the window
+        self.window_steps = 0_usize;
+        self.window_arrivals.iter_mut().for_each(|count| *count = 0_usize);
+        self.window_departures.iter_mut().for_each(|count| *count = 0_usize);
+    }
+
+    /// Get the total number of arrivals on each floor of the building, via its
+    /// entrances
+    fn get_arrivals_per_floor(building: &Building) -> Vec<usize> {
+        let mut arrivals: Vec<usize> = vec![0_usize; building.floors.len()];
+        for entrance in building.get_entrances().iter() {
+            arrivals[entrance.floor_index] += entrance.tot_arrived;
+        }
+        arrivals
+    }
+
+    /// Get the total number of people who decided to leave the building from each floor
+    fn get_departures_per_floor(building: &Building) -> Vec<usize> {
+        building.floors.iter().map(|floor| floor.tot_leaving).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use super::*;
+
+    //Constants defining the forecasters' period and buckets in the tests
+    const PERIOD: usize = 20_usize;
+    const NUM_BUCKETS: usize = 4_usize;
+
+    //Initialize a building with room for every arrival
+    fn new_building() -> Building {
+        Building::from(
+            4_usize,
+            1_usize,
+            1.0_f64,
+            10000_usize,
+            10_usize,
+            5.0_f64,
+            2.5_f64,
+            0.5_f64
+        )
+    }
+
+    //Run a building for three periods and one step, so every bucket is learned three
+    //times, with as many arrivals per time step as the bucket index plus the period
+    //index, then return the building's trained forecaster
+    fn train(method: ForecastMethod) -> DemandForecaster {
+        let mut building: Building = new_building();
+        building.demand_forecaster = Some(DemandForecaster::from(&building, PERIOD, NUM_BUCKETS, method));
+        let mut rng: StdRng = StdRng::seed_from_u64(0_u64);
+        for step in 0_usize..3_usize * PERIOD + 1_usize {
+            let num_arrivals: usize = (step % PERIOD) * NUM_BUCKETS / PERIOD + step / PERIOD;
+            building.begin_step_with_arrivals(Some(num_arrivals), &mut rng);
+            building.end_step().unwrap();
+        }
+        building.demand_forecaster.unwrap()
+    }
+
+    #[test]
+    fn buckets_divide_the_period() {
+        let forecaster: DemandForecaster = DemandForecaster::from(&new_building(), PERIOD, NUM_BUCKETS, ForecastMethod::BucketAverage);
+        let buckets: Vec<usize> = [0_usize, 4_usize, 5_usize, 19_usize, 20_usize, 45_usize].iter()
+            .map(|time_step| forecaster.get_bucket(*time_step))
+            .collect();
+        assert_eq!(buckets, vec![0_usize, 0_usize, 1_usize, 3_usize, 0_usize, 1_usize]);
+    }
+
+    #[test]
+    fn num_buckets_is_clamped_to_period() {
+        let forecaster: DemandForecaster = DemandForecaster::from(&new_building(), 3_usize, 10_usize, ForecastMethod::BucketAverage);
+        assert_eq!(forecaster.num_buckets, 3_usize);
+        assert_eq!(forecaster.get_bucket(2_usize), 2_usize);
+        let forecaster: DemandForecaster = DemandForecaster::from(&new_building(), 0_usize, 0_usize, ForecastMethod::BucketAverage);
+        assert_eq!((forecaster.period, forecaster.num_buckets), (1_usize, 1_usize));
+        assert_eq!(forecaster.get_bucket(7_usize), 0_usize);
+    }
+
+    #[test]
+    fn bucket_average_weighs_periods_equally() {
+        let forecaster: DemandForecaster = train(ForecastMethod::BucketAverage);
+        for bucket in 0_usize..NUM_BUCKETS {
+            let time_step: usize = bucket * PERIOD / NUM_BUCKETS;
+            assert_eq!(forecaster.get_num_periods_observed(time_step), 3_usize);
+            let rate: f64 = forecaster.get_arrival_rates(time_step)[0];
+            assert!((rate - (bucket as f64 + 1_f64)).abs() < 1e-9_f64);
+            assert_eq!(&forecaster.get_arrival_rates(time_step)[1..], &[0_f64; 3]);
+        }
+    }
+
+    #[test]
+    fn exponential_smoothing_weighs_recent_periods_more() {
+        let forecaster: DemandForecaster = train(ForecastMethod::ExponentialSmoothing { alpha: 0.5_f64 });
+        for bucket in 0_usize..NUM_BUCKETS {
+            let time_step: usize = bucket * PERIOD / NUM_BUCKETS;
+            let rate: f64 = forecaster.get_arrival_rates(time_step)[0];
+            assert!((rate - (bucket as f64 + 1.25_f64)).abs() < 1e-9_f64);
+        }
+    }
+
+    #[test]
+    fn forecast_demand_wraps_around_the_period() {
+        let forecaster: DemandForecaster = train(ForecastMethod::BucketAverage);
+        let demand: Vec<f64> = forecaster.forecast_demand(PERIOD - 2_usize, 4_usize);
+        for (i, floor_demand) in demand.iter().enumerate() {
+            let last_bucket: f64 = forecaster.get_arrival_rates(PERIOD - 1_usize)[i] + forecaster.get_departure_rates(PERIOD - 1_usize)[i];
+            let first_bucket: f64 = forecaster.get_arrival_rates(0_usize)[i] + forecaster.get_departure_rates(0_usize)[i];
+            assert!((floor_demand - 2_f64 * (last_bucket + first_bucket)).abs() < 1e-9_f64);
+        }
+        assert!(demand[0] >= 2_f64 * (4_f64 + 1_f64) - 1e-9_f64);
+    }
+}
\ No newline at end of file
@@ -1,8 +1,30 @@
 pub mod building;
+pub mod campus;
+pub mod command;
 pub mod controller;
 pub mod elevator;
 pub mod elevators;
+pub mod emergency;
+pub mod entrance;
+pub mod env;
+pub mod export;
+pub mod external;
 pub mod floor;
 pub mod floors;
+pub mod forecast;
+pub mod metrics;
+pub mod optimizer;
+pub mod outcome;
+pub mod parking;
 pub mod people;
-pub mod person;
\ No newline at end of file
+pub mod person;
+pub mod registry;
+pub mod render;
+pub mod replay;
+pub mod scenario;
+#[cfg(feature = "scripting")]
+pub mod script;
+pub mod simulator;
+pub mod trajectory;
+pub mod validation;
+pub mod view;
\ No newline at end of file
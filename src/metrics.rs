@@ -0,0 +1,316 @@
+//Import standard/imported modules
+use std::io::{self, Write};
+use serde_json::{Number, Value};
+
+//Import source modules
+use crate::building::Building;
+use crate::people::People;
+
+/// # `MetricColumn` type
+///
+/// A `MetricColumn` computes a user-defined value from the building, sampled by a
+/// `MetricsRecorder` each time step alongside its built-in columns.
+pub type MetricColumn = Box<dyn Fn(&Building) -> f64>;
+
+/// # `MetricsRecorder` struct
+///
+/// A `MetricsRecorder` samples per-step values from a `Building` into columnar
+/// buffers, one buffer per column, which can be read directly or flushed as CSV or
+/// JSON Lines.  The built-in columns are, in order:
+///
+/// - `step`: the building's time step
+/// - `people_in_building`: the number of people in the building
+/// - `waiting_floor_<i>`: the number of people waiting on each floor
+/// - `car_<i>_floor`, `car_<i>_people`, and `car_<i>_load`: the floor, the number of
+///   people, and the load (in kg) of each elevator
+/// - `entrance_<i>_arrived` and `entrance_<i>_departed`: the total number of people who
+///   arrived and departed through each entrance
+/// - `energy`: the energy spent by the elevators during the time step
+/// - `tips`: the tips collected during the time step
+/// - `avg_wait_time`, `avg_energy`, and `tot_tips`: the building's cumulative metrics
+///
+/// Custom columns follow the built-in columns in the order they were added.  The
+/// number of floor, car, and entrance columns is fixed by the building the recorder is
+/// created from, so the recorder should only sample that building.
+pub struct MetricsRecorder {
+    pub columns: Vec<String>,
+    pub values: Vec<Vec<f64>>,
+    custom_columns: Vec<MetricColumn>,
+    num_samples: usize,
+    prev_tot_energy: f64,
+    prev_tot_tips: f64,
+    header_written: bool
+}
+
+//Implement the MetricsRecorder interface
+impl MetricsRecorder {
+    /// Initialize a new MetricsRecorder given the building it will sample.  The
+    /// building's current cumulative energy and tips are used as the baseline for the
+    /// first sample's per-step values.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let my_building: Building = Building::from(
+    ///     4_usize,
+    ///     2_usize,
+    ///     0.5_f64,
+    ///     5.0_f64,
+    ///     2.5_f64,
+    ///     0.5_f64
+    /// );
+    /// let my_recorder: MetricsRecorder = MetricsRecorder::from(&my_building);
+    /// ```
+    pub fn from(building: &Building) -> MetricsRecorder {
+        //Initialize the names of the built-in columns
+        let mut columns: Vec<String> = vec![String::from("step"), String::from("people_in_building")];
+        for i in 0..building.floors.len() {
+            columns.push(format!("waiting_floor_{}", i));
+        }
+        for i in 0..building.elevators.len() {
+            columns.push(format!("car_{}_floor", i));
+            columns.push(format!("car_{}_people", i));
+            columns.push(format!("car_{}_load", i));
+        }
+        for i in 0..building.get_entrances().len() {
+            columns.push(format!("entrance_{}_arrived", i));
+            columns.push(format!("entrance_{}_departed", i));
+        }
+        for name in ["energy", "tips", "avg_wait_time", "avg_energy", "tot_tips"] {
+            columns.push(String::from(name));
+        }
+
+        //Initialize the recorder with an empty buffer per column
+        let values: Vec<Vec<f64>> = vec![Vec::new(); columns.len()];
+        MetricsRecorder {
+            columns: columns,
+            values: values,
+            custom_columns: Vec::new(),
+            num_samples: 0_usize,
+            prev_tot_energy: building.avg_energy * building.time_step as f64,
+            prev_tot_tips: building.tot_tips,
+            header_written: false
+        }
+    }
+
+    /// Add a custom column given its name and the function computing its value.  If
+    /// samples were already recorded, then the column is backfilled with `NaN`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// my_recorder.add_column("abandoned", Box::new(|building: &Building| {
+    ///     building.get_num_abandoned() as f64
+    /// }));
+    /// ```
+    pub fn add_column(&mut self, name: &str, column: MetricColumn) {
+        self.columns.push(String::from(name));
+        self.values.push(vec![f64::NAN; self.num_samples]);
+        self.custom_columns.push(column);
+    }
+
+    /// Get the number of samples currently buffered
+    pub fn get_num_samples(&self) -> usize {
+        self.num_samples
+    }
+
+    /// Get the buffered values of a column given its name, or `None` if there is no
+    /// column with that name
+    pub fn get_column(&self, name: &str) -> Option<&[f64]> {
+        self.columns.iter()
+            .position(|column| column == name)
+            .map(|i| self.values[i].as_slice())
+    }
+
+    /// Sample the building's values into the buffers.  Generally called once after
+    /// each time step.
+    pub fn sample(&mut self, building: &Building) {
+        //Calculate the energy spent and the tips collected since the last sample.  If
+        //the tips were collected from the building in the meantime, then the building's
+        //total restarted from zero.
+        let tot_energy: f64 = building.avg_energy * building.time_step as f64;
+        let energy: f64 = tot_energy - self.prev_tot_energy;
+        let tips: f64 = if building.tot_tips >= self.prev_tot_tips {
+            building.tot_tips - self.prev_tot_tips
+        } else {
+            building.tot_tips
+        };
+        self.prev_tot_energy = tot_energy;
+        self.prev_tot_tips = building.tot_tips;
+
+        //Gather the row of built-in values
+        let mut row: Vec<f64> = vec![building.time_step as f64, building.get_num_people_in_building() as f64];
+        for floor in building.floors.iter() {
+            row.push(floor.get_num_people_waiting() as f64);
+        }
+        for elevator in building.elevators.iter() {
+            row.push(elevator.floor_on as f64);
+            row.push(elevator.get_num_people() as f64);
+            row.push(elevator.get_load());
+        }
+        for entrance in building.get_entrances().iter() {
+            row.push(entrance.tot_arrived as f64);
+            row.push(entrance.tot_departed as f64);
+        }
+        row.extend([energy, tips, building.avg_wait_time, building.avg_energy, building.tot_tips]);
+
+        //Gather the custom values
+        for column in self.custom_columns.iter() {
+            row.push(column(building));
+        }
+
+        //Append the row to the buffers
+        for (buffer, value) in self.values.iter_mut().zip(row) {
+            buffer.push(value);
+        }
+        self.num_samples += 1_usize;
+    }
+
+    /// Clear the buffered samples, keeping the columns
+    pub fn clear(&mut self) {
+        for buffer in self.values.iter_mut() {
+            buffer.clear();
+        }
+        self.num_samples = 0_usize;
+    }
+
+    /// Write the buffered samples as CSV rows, then clear the buffers.  The header row
+    /// is written before the rows of the first flush only, so that repeated flushes to
+    /// the same writer form a single CSV file.
+    pub fn flush_csv(&mut self, writer: &mut dyn Write) -> io::Result<()> {
+        //Write the header on the first flush
+        if !self.header_written {
+            writeln!(writer, "{}", self.columns.join(","))?;
+            self.header_written = true;
+        }
+
+        //Write each buffered sample as a row
+        for i in 0..self.num_samples {
+            let row: Vec<String> = self.values.iter()
+                .map(|buffer| buffer[i].to_string())
+                .collect();
+            writeln!(writer, "{}", row.join(","))?;
+        }
+
+        //Clear the buffers
+        self.clear();
+        Ok(())
+    }
+
+    /// Write the buffered samples as JSON Lines, one object per sample keyed by column
+    /// name in column order, then clear the buffers.  Whole numbers are written as
+    /// integers, and values which are not finite are written as `null`.
+    pub fn flush_jsonl(&mut self, writer: &mut dyn Write) -> io::Result<()> {
+        //Write each buffered sample as a JSON object
+        for i in 0..self.num_samples {
+            let fields: Vec<String> = self.columns.iter()
+                .zip(self.values.iter())
+                .map(|(name, buffer)| format!("{}:{}", Value::from(name.as_str()), MetricsRecorder::get_json_value(buffer[i])))
+                .collect();
+            writeln!(writer, "{{{}}}", fields.join(","))?;
+        }
+
+        //Clear the buffers
+        self.clear();
+        Ok(())
+    }
+
+    /// Convert a sampled value into a JSON value
+    fn get_json_value(value: f64) -> Value {
+        if value.fract() == 0.0_f64 && value.abs() < 9_007_199_254_740_992.0_f64 {
+            Value::from(value as i64)
+        } else {
+            Number::from_f64(value).map_or(Value::Null, Value::Number)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use super::*;
+
+    //Run a 3-floor, 2-elevator building for the given number of time steps, sampling
+    //it with a recorder which has a custom column, and return the recorder
+    fn record_run(num_steps: usize) -> MetricsRecorder {
+        let mut building: Building = Building::from(
+            3_usize,
+            2_usize,
+            1.0_f64,
+            100_usize,
+            10_usize,
+            5.0_f64,
+            2.5_f64,
+            0.5_f64
+        );
+        let mut recorder: MetricsRecorder = MetricsRecorder::from(&building);
+        recorder.add_column("arrived", Box::new(|building: &Building| building.tot_arrived as f64));
+        let mut rng: StdRng = StdRng::seed_from_u64(0_u64);
+        for _ in 0_usize..num_steps {
+            building.begin_step(&mut rng);
+            building.end_step().unwrap();
+            recorder.sample(&building);
+        }
+        recorder
+    }
+
+    //The documented columns of a 3-floor, 2-elevator, 1-entrance building, followed
+    //by the custom column
+    const COLUMNS: [&str; 19] = [
+        "step", "people_in_building",
+        "waiting_floor_0", "waiting_floor_1", "waiting_floor_2",
+        "car_0_floor", "car_0_people", "car_0_load",
+        "car_1_floor", "car_1_people", "car_1_load",
+        "entrance_0_arrived", "entrance_0_departed",
+        "energy", "tips", "avg_wait_time", "avg_energy", "tot_tips",
+        "arrived"
+    ];
+
+    #[test]
+    fn csv_has_documented_columns() {
+        let mut recorder: MetricsRecorder = record_run(5_usize);
+        let mut output: Vec<u8> = Vec::new();
+        recorder.flush_csv(&mut output).unwrap();
+        let output: String = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0], COLUMNS.join(","));
+        assert_eq!(lines.len(), 6_usize);
+        for (i, line) in lines[1..].iter().enumerate() {
+            let fields: Vec<&str> = line.split(',').collect();
+            assert_eq!(fields.len(), COLUMNS.len());
+            assert_eq!(fields[0], (i + 1_usize).to_string());
+        }
+        assert_eq!(recorder.get_num_samples(), 0_usize);
+
+        //Later flushes append rows without repeating the header
+        let mut output: Vec<u8> = Vec::new();
+        recorder.flush_csv(&mut output).unwrap();
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn jsonl_has_documented_columns_in_order() {
+        let mut recorder: MetricsRecorder = record_run(5_usize);
+        recorder.add_column("missing", Box::new(|_building: &Building| f64::NAN));
+        let mut output: Vec<u8> = Vec::new();
+        recorder.flush_jsonl(&mut output).unwrap();
+        let output: String = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 5_usize);
+        for (i, line) in lines.iter().enumerate() {
+            //Check the keys appear in column order
+            let positions: Vec<usize> = COLUMNS.iter()
+                .chain(["missing"].iter())
+                .map(|column| line.find(&format!("\"{}\":", column)).unwrap())
+                .collect();
+            assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+
+            //Check the values
+            let sample: Value = serde_json::from_str(line).unwrap();
+            assert_eq!(sample.as_object().unwrap().len(), COLUMNS.len() + 1_usize);
+            assert_eq!(sample["step"], Value::from(i + 1_usize));
+            assert_eq!(sample["missing"], Value::Null);
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,308 @@
+//Import standard/imported modules
+use rand::Rng;
+use rand::rngs::StdRng;
+use rand::distributions::Distribution;
+use statrs::distribution::Normal;
+
+//Import source modules
+use crate::building::Building;
+use crate::controller::ElevatorController;
+use crate::registry::{ControllerFactory, ControllerParams};
+use crate::scenario::Scenario;
+use crate::simulator::Simulator;
+
+/// # `ParamRange` struct
+///
+/// A `ParamRange` describes the range searched for a single named controller
+/// parameter by an `Optimizer`.  Grid searches try `num_values` evenly spaced values
+/// from `min` to `max`, while random and evolutionary searches sample anywhere in the
+/// range.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParamRange {
+    pub name: String,
+    pub min: f64,
+    pub max: f64,
+    pub num_values: usize
+}
+
+//Implement the ParamRange interface
+impl ParamRange {
+    /// Initialize a new ParamRange given the name of the parameter, the bounds of its
+    /// range, and the number of values tried for it in a grid search.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let my_range: ParamRange = ParamRange::from("p_rational", 0.0_f64, 1.0_f64, 5_usize);
+    /// ```
+    pub fn from(name: &str, min: f64, max: f64, num_values: usize) -> ParamRange {
+        ParamRange {
+            name: String::from(name),
+            min: min,
+            max: max,
+            num_values: num_values
+        }
+    }
+
+    /// Get the evenly spaced values of the range tried in a grid search
+    pub fn get_grid_values(&self) -> Vec<f64> {
+        if self.num_values <= 1_usize {
+            return vec![self.min];
+        }
+        let step: f64 = (self.max - self.min) / (self.num_values - 1_usize) as f64;
+        (0..self.num_values).map(|i| self.min + step * i as f64).collect()
+    }
+
+    /// Sample a value uniformly at random from the range
+    pub fn gen_value(&self, rng: &mut impl Rng) -> f64 {
+        if self.max <= self.min {
+            return self.min;
+        }
+        rng.gen_range(self.min..=self.max)
+    }
+
+    /// Clamp a value to the range
+    pub fn clamp(&self, value: f64) -> f64 {
+        value.max(self.min).min(self.max)
+    }
+}
+
+/// # `SearchStrategy` enum
+///
+/// A `SearchStrategy` decides how an `Optimizer` explores the parameter space.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SearchStrategy {
+    /// Try every combination of the ranges' grid values
+    Grid,
+    /// Try the given number of parameter sets sampled uniformly at random
+    Random { num_samples: usize },
+    /// Evolve a population of parameter sets over the given number of generations.
+    /// Each generation the better half of the population survives, and is copied
+    /// with Gaussian mutations to replace the worse half.  The standard deviation
+    /// of the mutations is the given fraction of each range's width.
+    Evolutionary { population_size: usize, num_generations: usize, mutation_scale: f64 }
+}
+
+/// # `Trial` struct
+///
+/// A `Trial` holds a set of controller parameters tried by an `Optimizer`, along with
+/// the objective's score for each of the scenario's runs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Trial {
+    pub params: ControllerParams,
+    pub scores: Vec<f64>
+}
+
+//Implement the Trial interface
+impl Trial {
+    /// Get the mean score across the trial's runs
+    pub fn get_mean_score(&self) -> f64 {
+        if self.scores.is_empty() {
+            return f64::INFINITY;
+        }
+        self.scores.iter().sum::<f64>() / self.scores.len() as f64
+    }
+
+    /// Get the standard deviation of the scores across the trial's runs
+    pub fn get_std_dev_score(&self) -> f64 {
+        if self.scores.len() < 2_usize {
+            return 0.0_f64;
+        }
+        let mean_score: f64 = self.get_mean_score();
+        let sum_sq: f64 = self.scores.iter().map(|score| (score - mean_score).powi(2)).sum();
+        (sum_sq / (self.scores.len() - 1_usize) as f64).sqrt()
+    }
+
+    /// Get the minimum score across the trial's runs
+    pub fn get_min_score(&self) -> f64 {
+        self.scores.iter().cloned().fold(f64::INFINITY, f64::min)
+    }
+
+    /// Get the maximum score across the trial's runs
+    pub fn get_max_score(&self) -> f64 {
+        self.scores.iter().cloned().fold(f64::NEG_INFINITY, f64::max)
+    }
+}
+
+/// # `OptimizationResult` struct
+///
+/// An `OptimizationResult` holds the best `Trial` found by an `Optimizer`, that is,
+/// the trial with the lowest mean score, along with every trial it ran.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OptimizationResult {
+    pub best: Trial,
+    pub trials: Vec<Trial>
+}
+
+/// # `Optimizer` struct
+///
+/// An `Optimizer` tunes the parameters of a controller built by a `ControllerFactory`.
+/// It runs the controller through a `Scenario` once per seed for each set of
+/// parameters it tries, and scores each run with an objective over the final
+/// `Building`, such as its average wait time.  Lower scores are better.  Runs which
+/// violate the building's invariants in debug mode score `f64::INFINITY`.
+pub struct Optimizer {
+    pub scenario: Scenario,
+    pub param_ranges: Vec<ParamRange>,
+    pub strategy: SearchStrategy,
+    objective: Box<dyn Fn(&Building) -> f64>,
+    rng: StdRng
+}
+
+//Implement the Optimizer interface
+impl Optimizer {
+    /// Initialize a new Optimizer given a scenario, the ranges of the parameters to
+    /// search, the search strategy, the objective to minimize, and an `StdRng` (from
+    /// the rand library) used by the random and evolutionary searches.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let my_optimizer: Optimizer = Optimizer::from(
+    ///     my_scenario,
+    ///     vec![ParamRange::from("p_rational", 0.0_f64, 1.0_f64, 5_usize)],
+    ///     SearchStrategy::Grid,
+    ///     Box::new(|building: &Building| building.avg_wait_time),
+    ///     StdRng::seed_from_u64(0_u64)
+    /// );
+    /// ```
+    pub fn from(scenario: Scenario, param_ranges: Vec<ParamRange>, strategy: SearchStrategy,
+        objective: Box<dyn Fn(&Building) -> f64>, rng: StdRng) -> Optimizer {
+        Optimizer {
+            scenario: scenario,
+            param_ranges: param_ranges,
+            strategy: strategy,
+            objective: objective,
+            rng: rng
+        }
+    }
+
+    /// Run the scenario once per seed with a controller built by the factory from the
+    /// given parameters, and return the trial holding the score of each run.
+    pub fn evaluate(&self, factory: &ControllerFactory, params: &ControllerParams) -> Trial {
+        //Loop through the scenario's seeds and score each run
+        let mut scores: Vec<f64> = Vec::new();
+        for seed in self.scenario.seeds.iter() {
+            let controller: Box<dyn ElevatorController> = factory(&self.scenario.building, params);
+            let mut simulator: Simulator = Simulator::from(self.scenario.building.clone(), controller);
+            let score: f64 = match self.scenario.run(&mut simulator, *seed) {
+                Ok(_) => (self.objective)(&simulator.building),
+                Err(_) => f64::INFINITY
+            };
+            scores.push(score);
+        }
+
+        //Return the trial
+        Trial {
+            params: params.clone(),
+            scores: scores
+        }
+    }
+
+    /// Convert a vector of values, one per parameter range, into controller parameters
+    fn get_params(&self, values: &[f64]) -> ControllerParams {
+        let mut params: ControllerParams = ControllerParams::new();
+        for (param_range, value) in self.param_ranges.iter().zip(values.iter()) {
+            params = params.with(&param_range.name, *value);
+        }
+        params
+    }
+
+    /// Generate every combination of the parameter ranges' grid values
+    fn gen_grid(&self) -> Vec<Vec<f64>> {
+        let mut grid: Vec<Vec<f64>> = vec![Vec::new()];
+        for param_range in self.param_ranges.iter() {
+            let mut new_grid: Vec<Vec<f64>> = Vec::new();
+            for values in grid.iter() {
+                for value in param_range.get_grid_values() {
+                    let mut new_values: Vec<f64> = values.clone();
+                    new_values.push(value);
+                    new_grid.push(new_values);
+                }
+            }
+            grid = new_grid;
+        }
+        grid
+    }
+
+    /// Generate a vector of values, one per parameter range, sampled uniformly at random
+    fn gen_random_values(&mut self) -> Vec<f64> {
+        let mut values: Vec<f64> = Vec::new();
+        for param_range in self.param_ranges.iter() {
+            values.push(param_range.gen_value(&mut self.rng));
+        }
+        values
+    }
+
+    /// Mutate a vector of values, one per parameter range, with Gaussian noise whose
+    /// standard deviation is the given fraction of each range's width
+    fn gen_mutated_values(&mut self, values: &[f64], mutation_scale: f64) -> Vec<f64> {
+        let mut mutated_values: Vec<f64> = Vec::new();
+        for (param_range, value) in self.param_ranges.iter().zip(values.iter()) {
+            let std_dev: f64 = mutation_scale * (param_range.max - param_range.min);
+            let mutated_value: f64 = match Normal::new(*value, std_dev) {
+                Ok(dst_mutation) if std_dev > 0.0_f64 => dst_mutation.sample(&mut self.rng),
+                _ => *value
+            };
+            mutated_values.push(param_range.clamp(mutated_value));
+        }
+        mutated_values
+    }
+
+    /// Search the parameter space according to the optimizer's strategy, running the
+    /// controllers built by the given factory through the scenario.  Returns the best
+    /// trial along with every trial run, or `None` if no trials were run.
+    pub fn optimize(&mut self, factory: &ControllerFactory) -> Option<OptimizationResult> {
+        //Run the trials according to the strategy
+        let mut trials: Vec<Trial> = Vec::new();
+        match self.strategy {
+            SearchStrategy::Grid => {
+                for values in self.gen_grid() {
+                    trials.push(self.evaluate(factory, &self.get_params(&values)));
+                }
+            },
+            SearchStrategy::Random { num_samples } => {
+                for _ in 0..num_samples {
+                    let values: Vec<f64> = self.gen_random_values();
+                    trials.push(self.evaluate(factory, &self.get_params(&values)));
+                }
+            },
+            SearchStrategy::Evolutionary { population_size, num_generations, mutation_scale } => {
+                //Initialize and evaluate a random population
+                let mut population: Vec<(Vec<f64>, f64)> = Vec::new();
+                for _ in 0..population_size {
+                    let values: Vec<f64> = self.gen_random_values();
+                    let trial: Trial = self.evaluate(factory, &self.get_params(&values));
+                    population.push((values, trial.get_mean_score()));
+                    trials.push(trial);
+                }
+
+                //Loop through the generations
+                for _ in 0..num_generations {
+                    //Keep the better half of the population
+                    population.sort_by(|a, b| a.1.total_cmp(&b.1));
+                    let num_survivors: usize = population.len().div_ceil(2_usize);
+                    population.truncate(num_survivors);
+
+                    //Replace the worse half with mutated copies of the survivors
+                    for i in 0..(population_size.saturating_sub(num_survivors)) {
+                        let parent_values: Vec<f64> = population[i % num_survivors].0.clone();
+                        let values: Vec<f64> = self.gen_mutated_values(&parent_values, mutation_scale);
+                        let trial: Trial = self.evaluate(factory, &self.get_params(&values));
+                        population.push((values, trial.get_mean_score()));
+                        trials.push(trial);
+                    }
+                }
+            }
+        }
+
+        //Find the best trial and return the result
+        let best: Trial = trials.iter()
+            .min_by(|a, b| a.get_mean_score().total_cmp(&b.get_mean_score()))?
+            .clone();
+        Some(OptimizationResult {
+            best: best,
+            trials: trials
+        })
+    }
+}
\ No newline at end of file
@@ -0,0 +1,107 @@
+//Import standard/imported modules
+use rand::RngCore;
+
+/// # `BoolOutcome` enum
+///
+/// A `BoolOutcome` is a kind of random yes or no outcome generated during a time step.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BoolOutcome {
+    /// Whether an elevator in service breaks down
+    Breakdown,
+    /// Whether an arriving person brings along cargo
+    Cargo,
+    /// Whether a person not waiting for an elevator decides to leave the building
+    Departure
+}
+
+/// # `CountOutcome` enum
+///
+/// A `CountOutcome` is a kind of random count or index generated during a time step.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CountOutcome {
+    /// The number of time steps a broken down elevator takes to repair
+    RepairTime,
+    /// The number of people arriving through an entrance
+    NumArrivals,
+    /// The index of the entrance an arrival is assigned to, when the number of
+    /// arrivals is given rather than sampled per entrance
+    ArrivalEntrance,
+    /// The destination floor of an arriving person
+    Destination,
+    /// The patience of an arriving person, in time steps
+    Patience,
+    /// The index of the entrance an arriving person later exits through
+    ExitEntrance
+}
+
+/// # `ValueOutcome` enum
+///
+/// A `ValueOutcome` is a kind of random real value generated during a time step.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValueOutcome {
+    /// The mass of an arriving person, in kg
+    Mass,
+    /// The total value of the tips left by the people departing the building
+    Tips
+}
+
+/// # `OutcomeSource` trait
+///
+/// An `OutcomeSource` decides the random outcomes of a `Building`'s time steps, such as
+/// its arrivals, their destinations, departures, breakdowns, and tips.  Each outcome
+/// is requested along with a sampler which generates it from an rng, so a source may
+/// sample the outcome, record it, or return a previously recorded outcome without
+/// sampling at all.  The building requests its outcomes in a fixed order each time
+/// step, so a run is reproduced exactly by returning the same outcomes in the same
+/// order.
+pub trait OutcomeSource {
+    fn gen_bool(&mut self, kind: BoolOutcome, sampler: &mut dyn FnMut(&mut dyn RngCore) -> bool) -> bool;
+
+    fn gen_count(&mut self, kind: CountOutcome, sampler: &mut dyn FnMut(&mut dyn RngCore) -> usize) -> usize;
+
+    fn gen_value(&mut self, kind: ValueOutcome, sampler: &mut dyn FnMut(&mut dyn RngCore) -> f64) -> f64;
+}
+
+/// # `RngOutcomes` struct
+///
+/// An `RngOutcomes` implements the `OutcomeSource` trait.  It generates every outcome by
+/// sampling it from an rng, which is how a building is normally simulated.
+pub struct RngOutcomes<'a> {
+    rng: &'a mut dyn RngCore
+}
+
+//Implement the RngOutcomes interface
+impl<'a> RngOutcomes<'a> {
+    /// Initialize a new RngOutcomes given the rng to sample outcomes from
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let mut my_rng: StdRng = StdRng::seed_from_u64(0_u64);
+    /// let mut my_outcomes: RngOutcomes = RngOutcomes::from(&mut my_rng);
+    /// my_building.begin_step_with_outcomes(None, &mut my_outcomes);
+    /// ```
+    pub fn from(rng: &'a mut dyn RngCore) -> RngOutcomes<'a> {
+        RngOutcomes {
+            rng: rng
+        }
+    }
+}
+
+//Implement the OutcomeSource trait for the RngOutcomes
+impl OutcomeSource for RngOutcomes<'_> {
+    /// Sample the yes or no outcome from the rng
+    fn gen_bool(&mut self, _kind: BoolOutcome, sampler: &mut dyn FnMut(&mut dyn RngCore) -> bool) -> bool {
+        sampler(self.rng)
+    }
+
+    /// Sample the count outcome from the rng
+    fn gen_count(&mut self, _kind: CountOutcome, sampler: &mut dyn FnMut(&mut dyn RngCore) -> usize) -> usize {
+        sampler(self.rng)
+    }
+
+    /// Sample the real valued outcome from the rng
+    fn gen_value(&mut self, _kind: ValueOutcome, sampler: &mut dyn FnMut(&mut dyn RngCore) -> f64) -> f64 {
+        sampler(self.rng)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,203 @@
+//Import source modules
+use crate::view::BuildingView;
+
+/// # `ParkingPolicy` trait
+///
+/// A `ParkingPolicy` implementation decides where an idle elevator should park, and
+/// whether idle elevators should be put into a low-power sleep state.  It is used by
+/// `ElevatorController` implementations to send idle elevators to their home floors.
+pub trait ParkingPolicy {
+    /// Expected to determine the floor on which the elevator at the given index
+    /// should park while it is idle, given a view of the building.  Returns `None`
+    /// if the elevator should stay where it is.
+    fn get_parking_floor(&self, view: &BuildingView, elevator_index: usize) -> Option<usize>;
+
+    /// Expected to return the number of consecutive idle time steps after which an
+    /// elevator is put into its low-power sleep state, or `None` if elevators never
+    /// sleep.
+    fn get_sleep_after(&self) -> Option<usize>;
+
+    /// Expected to return the number of time steps it takes a sleeping elevator to
+    /// wake up before it is able to move again.
+    fn get_wake_delay(&self) -> usize;
+}
+
+/// # `HomeParkingPolicy` struct
+///
+/// A `HomeParkingPolicy` implements the `ParkingPolicy` trait.  It sends each idle
+/// elevator to a home floor, such as the lobby during the morning rush.  If there
+/// are fewer home floors than elevators, then the home floors are assigned to the
+/// elevators in a round-robin fashion.
+pub struct HomeParkingPolicy {
+    pub home_floors: Vec<usize>,
+    pub sleep_after: Option<usize>,
+    pub wake_delay: usize
+}
+
+//Implement the HomeParkingPolicy interface
+impl HomeParkingPolicy {
+    /// Initialize a new HomeParkingPolicy given a vector of home floors, the
+    /// number of idle time steps after which elevators sleep, and the number of
+    /// time steps it takes an elevator to wake up.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let home_floors: Vec<usize> = vec![0_usize];
+    /// let sleep_after: Option<usize> = Some(10_usize);
+    /// let wake_delay: usize = 2_usize;
+    /// let my_policy: HomeParkingPolicy = HomeParkingPolicy::from(home_floors, sleep_after, wake_delay);
+    /// ```
+    pub fn from(home_floors: Vec<usize>, sleep_after: Option<usize>, wake_delay: usize) -> HomeParkingPolicy {
+        HomeParkingPolicy {
+            home_floors: home_floors,
+            sleep_after: sleep_after,
+            wake_delay: wake_delay
+        }
+    }
+}
+
+//Implement the ParkingPolicy trait for the HomeParkingPolicy
+impl ParkingPolicy for HomeParkingPolicy {
+    /// Return the home floor assigned to the elevator at the given index.  Home
+    /// floors beyond the top floor of the building are clamped to the top floor.
+    fn get_parking_floor(&self, view: &BuildingView, elevator_index: usize) -> Option<usize> {
+        //If there are no home floors or floors, then the elevator stays put
+        if self.home_floors.is_empty() || view.floors.is_empty() {
+            return None;
+        }
+
+        //Assign the home floor round-robin and clamp it to the building
+        let home_floor: usize = self.home_floors[elevator_index % self.home_floors.len()];
+        Some(home_floor.min(view.get_num_floors() - 1_usize))
+    }
+
+    /// Return the number of idle time steps after which elevators sleep
+    fn get_sleep_after(&self) -> Option<usize> {
+        self.sleep_after
+    }
+
+    /// Return the number of time steps it takes an elevator to wake up
+    fn get_wake_delay(&self) -> usize {
+        self.wake_delay
+    }
+}
+
+/// # `SpreadParkingPolicy` struct
+///
+/// A `SpreadParkingPolicy` implements the `ParkingPolicy` trait.  It divides the
+/// building into one zone per elevator, and parks each idle elevator in the middle
+/// of its zone so that the elevators are spread evenly throughout the building.
+pub struct SpreadParkingPolicy {
+    pub sleep_after: Option<usize>,
+    pub wake_delay: usize
+}
+
+//Implement the SpreadParkingPolicy interface
+impl SpreadParkingPolicy {
+    /// Initialize a new SpreadParkingPolicy given the number of idle time steps
+    /// after which elevators sleep, and the number of time steps it takes an
+    /// elevator to wake up.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let my_policy: SpreadParkingPolicy = SpreadParkingPolicy::from(None, 0_usize);
+    /// ```
+    pub fn from(sleep_after: Option<usize>, wake_delay: usize) -> SpreadParkingPolicy {
+        SpreadParkingPolicy {
+            sleep_after: sleep_after,
+            wake_delay: wake_delay
+        }
+    }
+}
+
+//Implement the ParkingPolicy trait for the SpreadParkingPolicy
+impl ParkingPolicy for SpreadParkingPolicy {
+    /// Return the floor in the middle of the zone assigned to the elevator at the
+    /// given index.
+    fn get_parking_floor(&self, view: &BuildingView, elevator_index: usize) -> Option<usize> {
+        //Get the number of floors and elevators in the building
+        let num_floors: usize = view.get_num_floors();
+        let num_elevators: usize = view.get_num_elevators();
+        if num_floors == 0_usize || num_elevators == 0_usize {
+            return None;
+        }
+
+        //Calculate the middle floor of the elevator's zone
+        let zone_floor: usize = ((2_usize * elevator_index + 1_usize) * num_floors) / (2_usize * num_elevators);
+        Some(zone_floor.min(num_floors - 1_usize))
+    }
+
+    /// Return the number of idle time steps after which elevators sleep
+    fn get_sleep_after(&self) -> Option<usize> {
+        self.sleep_after
+    }
+
+    /// Return the number of time steps it takes an elevator to wake up
+    fn get_wake_delay(&self) -> usize {
+        self.wake_delay
+    }
+}
+
+/// # `DemandParkingPolicy` struct
+///
+/// A `DemandParkingPolicy` implements the `ParkingPolicy` trait.  It parks each idle
+/// elevator on one of the floors where the most trips are expected to start, according
+/// to the demand forecast in the building view, so that elevators are waiting where
+/// they will be needed, such as the lobby ahead of the morning rush.  The floor with
+/// the highest demand is assigned to the first elevator, the next highest to the
+/// second, and so on, in a round-robin fashion.  If the building has no demand
+/// forecaster, or no demand is forecast, then the elevators stay where they are.
+pub struct DemandParkingPolicy {
+    pub sleep_after: Option<usize>,
+    pub wake_delay: usize
+}
+
+//Implement the DemandParkingPolicy interface
+impl DemandParkingPolicy {
+    /// Initialize a new DemandParkingPolicy given the number of idle time steps
+    /// after which elevators sleep, and the number of time steps it takes an
+    /// elevator to wake up.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let my_policy: DemandParkingPolicy = DemandParkingPolicy::from(None, 0_usize);
+    /// ```
+    pub fn from(sleep_after: Option<usize>, wake_delay: usize) -> DemandParkingPolicy {
+        DemandParkingPolicy {
+            sleep_after: sleep_after,
+            wake_delay: wake_delay
+        }
+    }
+}
+
+//Implement the ParkingPolicy trait for the DemandParkingPolicy
+impl ParkingPolicy for DemandParkingPolicy {
+    /// Return the floor assigned to the elevator at the given index by ranking the
+    /// floors with forecast demand, highest first, breaking ties by the lower floor.
+    fn get_parking_floor(&self, view: &BuildingView, elevator_index: usize) -> Option<usize> {
+        //Rank the floors with forecast demand
+        let mut demand_floors: Vec<usize> = (0_usize..view.demand_forecast.len())
+            .filter(|i| view.demand_forecast[*i] > 0_f64)
+            .collect();
+        if demand_floors.is_empty() {
+            return None;
+        }
+        demand_floors.sort_by(|a, b| view.demand_forecast[*b].total_cmp(&view.demand_forecast[*a]));
+
+        //Assign the floor round-robin
+        Some(demand_floors[elevator_index % demand_floors.len()])
+    }
+
+    /// Return the number of idle time steps after which elevators sleep
+    fn get_sleep_after(&self) -> Option<usize> {
+        self.sleep_after
+    }
+
+    /// Return the number of time steps it takes an elevator to wake up
+    fn get_wake_delay(&self) -> usize {
+        self.wake_delay
+    }
+}
\ No newline at end of file
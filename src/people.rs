@@ -1,5 +1,5 @@
 //Import standard/imported modules
-use rand::Rng;
+use rand::RngCore;
 
 //Import source modules
 use crate::person::Person;
@@ -11,7 +11,7 @@ use crate::person::Person;
 /// for managing `Person`s in aggregate.
 pub trait People {
     /// Expected to generate the number of tips to collect from the people
-    fn gen_num_tips(&self, rng: &mut impl Rng) -> usize;
+    fn gen_num_tips(&self, rng: &mut dyn RngCore) -> usize;
 
     /// Expected to determine the destination floors for all people and return it as
     /// a vector.
@@ -31,6 +31,14 @@ pub trait People {
     /// and aggregate the total into a usize.
     fn get_aggregate_wait_time(&self) -> usize;
 
+    /// Expected to aggregate the total mass (in kg) of the people and return it as an
+    /// f64.
+    fn get_aggregate_mass(&self) -> f64;
+
+    /// Expected to aggregate the total volume (in cubic meters) of the luggage and carts
+    /// carried by the people and return it as an f64.
+    fn get_aggregate_volume(&self) -> f64;
+
     /// Expected to determine whether anyone in the collection of people are going to
     /// a given floor, returning a bool which is true if so, and false if not.
     fn are_people_going_to_floor(&self, floor_index: usize) -> bool;
@@ -46,11 +54,15 @@ pub trait People {
     /// Expected to reset the wait times (to `0_usize`) among all people who have a
     /// nonzero wait time and are on their desired floor.
     fn reset_wait_times(&mut self);
+
+    /// Expected to evacuate all people by directing them to the given exit floor and
+    /// marking them as leaving.
+    fn evacuate(&mut self, floor_to: usize);
 }
 
 impl People for Vec<Person> {
     /// Generates the number of people among the collection of people who will tip.
-    fn gen_num_tips(&self, rng: &mut impl Rng) -> usize {
+    fn gen_num_tips(&self, rng: &mut dyn RngCore) -> usize {
         //Initialize a counter for the number of people who will tip
         let mut num_tips: usize = 0_usize;
 
@@ -143,6 +155,35 @@ impl People for Vec<Person> {
         aggregate_wait_time
     }
 
+    /// Aggregates the total mass (in kg) of the people and returns it as an f64.
+    fn get_aggregate_mass(&self) -> f64 {
+        //Initialize an f64 for the total mass of the people
+        let mut aggregate_mass: f64 = 0.0_f64;
+
+        //Loop through the vector of persons and add their mass
+        for pers in self.iter() {
+            aggregate_mass += pers.mass;
+        }
+
+        //Return the f64
+        aggregate_mass
+    }
+
+    /// Aggregates the total volume (in cubic meters) of the luggage and carts carried
+    /// by the people and returns it as an f64.
+    fn get_aggregate_volume(&self) -> f64 {
+        //Initialize an f64 for the total volume carried by the people
+        let mut aggregate_volume: f64 = 0.0_f64;
+
+        //Loop through the vector of persons and add their volume
+        for pers in self.iter() {
+            aggregate_volume += pers.volume;
+        }
+
+        //Return the f64
+        aggregate_volume
+    }
+
     /// Determines whether anyone in the collection of people are going to a given floor,
     /// and returns a bool which is true if so, and false if not.
     fn are_people_going_to_floor(&self, floor_index: usize) -> bool {
@@ -204,4 +245,13 @@ impl People for Vec<Person> {
             pers.reset_wait_time();
         }
     }
+
+    /// Evacuates all people by directing them to the given exit floor and marking them
+    /// as leaving.
+    fn evacuate(&mut self, floor_to: usize) {
+        //Loop through the people and evacuate them
+        for pers in self.iter_mut() {
+            pers.evacuate(floor_to);
+        }
+    }
 }
\ No newline at end of file
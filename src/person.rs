@@ -1,13 +1,21 @@
 //Import standard/imported libraries
 use std::fmt;
-use rand::Rng;
+use rand::RngCore;
 use rand::distributions::{Distribution, Uniform, Bernoulli};
 
+//Import source modules
+use crate::outcome::{OutcomeSource, BoolOutcome};
+
+/// The mass (in kg) of a `Person` who is not given a mass explicitly, also used as
+/// the mass of one person-equivalent when rating elevators and calculating energy
+pub const PERSON_MASS: f64 = 75.0_f64;
+
 /// # Person struct
 ///
 /// A `Person` is aggregated by floors and elevators, and transported between floors
 /// by elevators. The person struct generally should not be directly instantiated;
-/// instead it should be managed in aggregate via the `Building` type.
+/// instead it should be managed in aggregate via the `Building` type.  The person's
+/// `exit_floor` is the floor they head to once they decide to leave the building.
 #[derive(Clone)]
 pub struct Person {
     pub floor_on: usize,
@@ -16,6 +24,12 @@ pub struct Person {
     pub wait_time: usize,
     pub p_out: f64,
     pub p_tip: f64,
+    pub mass: f64,
+    pub volume: f64,
+    pub patience: Option<usize>,
+    pub balk_threshold: Option<usize>,
+    pub max_stairs: usize,
+    pub exit_floor: usize,
     dst_out: Bernoulli,
     dst_tip: Bernoulli
 }
@@ -27,7 +41,8 @@ pub struct Person {
 impl Person {
     /// Initialize a new person given that persons probability of leaving, the number of
     /// floors in the building, and an Rng implementation to randomize the person's
-    /// destination floor
+    /// destination floor.  The person is given the default `PERSON_MASS` and carries
+    /// no luggage.
     ///
     /// ### Example
     ///
@@ -36,11 +51,50 @@ impl Person {
     /// let p_tip: f64 = 0.2_f64; //Must be between 0 and 1
     /// let num_floors: usize = 5_usize;
     /// let my_rng = rand::thread_rng(); //From rand library
-    /// let my_pers: Person = Person::from(p_out, num_floors, &mut my_rng);
+    /// let my_pers: Person = Person::from(p_out, p_tip, num_floors, &mut my_rng);
     /// ```
-    pub fn from(p_out: f64, p_tip: f64, num_floors: usize, mut rng: &mut impl Rng) -> Person {
-        let dst_to = Uniform::new(0_usize, num_floors);
-        let floor_to: usize = dst_to.sample(&mut rng);
+    pub fn from(p_out: f64, p_tip: f64, num_floors: usize, rng: &mut dyn RngCore) -> Person {
+        Person::from_load(p_out, p_tip, num_floors, PERSON_MASS, 0.0_f64, rng)
+    }
+
+    /// Initialize a new person given that persons probability of leaving, the number of
+    /// floors in the building, the person's mass (in kg, including anything they carry),
+    /// the volume (in cubic meters) of any luggage or cart they bring along, and an Rng
+    /// implementation to randomize the person's destination floor.  The person starts
+    /// on the first floor and exits from it, which the `Building` updates if it has
+    /// other entrance floors.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// let p_out: f64 = 0.05_f64; //Must be between 0 and 1
+    /// let p_tip: f64 = 0.2_f64; //Must be between 0 and 1
+    /// let num_floors: usize = 5_usize;
+    /// let mass: f64 = 180.0_f64; //A person moving in with a loaded cart
+    /// let volume: f64 = 0.8_f64;
+    /// let my_rng = rand::thread_rng(); //From rand library
+    /// let my_pers: Person = Person::from_load(p_out, p_tip, num_floors, mass, volume, &mut my_rng);
+    /// ```
+    pub fn from_load(p_out: f64, p_tip: f64, num_floors: usize, mass: f64, volume: f64,
+                     rng: &mut dyn RngCore) -> Person {
+        let floor_to: usize = Person::gen_floor_to(num_floors, rng);
+        Person::from_trip(p_out, p_tip, floor_to, mass, volume)
+    }
+
+    /// Initialize a new person given that persons probability of leaving, their
+    /// destination floor, their mass (in kg, including anything they carry), and the
+    /// volume (in cubic meters) of any luggage or cart they bring along.  The person
+    /// starts on the first floor and exits from it.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// let p_out: f64 = 0.05_f64; //Must be between 0 and 1
+    /// let p_tip: f64 = 0.2_f64; //Must be between 0 and 1
+    /// let floor_to: usize = 3_usize;
+    /// let my_pers: Person = Person::from_trip(p_out, p_tip, floor_to, 75.0_f64, 0.0_f64);
+    /// ```
+    pub fn from_trip(p_out: f64, p_tip: f64, floor_to: usize, mass: f64, volume: f64) -> Person {
         Person {
             floor_on: 0_usize,
             floor_to: floor_to,
@@ -49,31 +103,85 @@ impl Person {
             p_out: p_out,
             dst_out: Bernoulli::new(p_out).unwrap(),
             p_tip: p_tip,
-            dst_tip: Bernoulli::new(p_tip).unwrap()
+            dst_tip: Bernoulli::new(p_tip).unwrap(),
+            mass: mass,
+            volume: volume,
+            patience: None,
+            balk_threshold: None,
+            max_stairs: 0_usize,
+            exit_floor: 0_usize
         }
     }
 
+    /// Generate a destination floor uniformly at random given the number of floors in
+    /// the building
+    pub fn gen_floor_to(num_floors: usize, mut rng: &mut dyn RngCore) -> usize {
+        let dst_to = Uniform::new(0_usize, num_floors);
+        dst_to.sample(&mut rng)
+    }
+
+    /// Determine whether the person is waiting, that is, not on their desired floor
+    pub fn is_waiting(&self) -> bool {
+        self.floor_on != self.floor_to
+    }
+
+    /// Determine whether the person balks, that is, refuses to join the queue for the
+    /// elevator, given the number of people already waiting in the queue.  A person
+    /// with no `balk_threshold` never balks.
+    pub fn is_balking(&self, queue_length: usize) -> bool {
+        match self.balk_threshold {
+            Some(balk_threshold) => self.is_waiting() && queue_length > balk_threshold,
+            None => false
+        }
+    }
+
+    /// Determine whether the person reneges, that is, gives up waiting for the elevator
+    /// because they have waited longer than their patience.  A person with no `patience`
+    /// never reneges.
+    pub fn is_reneging(&self) -> bool {
+        match self.patience {
+            Some(patience) => self.is_waiting() && self.wait_time > patience,
+            None => false
+        }
+    }
+
+    /// Determine whether the person takes the stairs instead of waiting for the elevator
+    /// because their trip spans at most `max_stairs` floors.
+    pub fn is_taking_stairs(&self) -> bool {
+        let trip_length: usize = self.floor_on.abs_diff(self.floor_to);
+        self.is_waiting() && trip_length <= self.max_stairs
+    }
+
     /// Sample a person's `dst_out` distribution to update the person's `is_leaving`
-    /// property randomly and return the result as a bool.  Or if the person is already
-    /// leaving then return the property as is.
-    pub fn gen_is_leaving(&mut self, mut rng: &mut impl Rng) -> bool {
+    /// property randomly and return the result as a bool, as decided by the given
+    /// `OutcomeSource`.  If the person decides to leave, then they head to their exit
+    /// floor.  Or if the person is already leaving then return the property as is.
+    pub fn gen_is_leaving(&mut self, outcomes: &mut dyn OutcomeSource) -> bool {
         //Check if the is_leaving boolean is true, if so return it
         if self.is_leaving {
             return self.is_leaving;
         }
 
         //If the person is not leaving, then randomly generate whether they wish to leave
-        let pers_is_leaving: bool = self.dst_out.sample(&mut rng);
+        let dst_out: Bernoulli = self.dst_out;
+        let pers_is_leaving: bool = outcomes.gen_bool(BoolOutcome::Departure, &mut |rng| dst_out.sample(rng));
         if pers_is_leaving {
-            self.floor_to = 0_usize;
+            self.floor_to = self.exit_floor;
             self.is_leaving = pers_is_leaving;
         }
         self.is_leaving
     }
 
+    /// Evacuate the person by directing them to the given exit floor and marking them
+    /// as leaving the building.
+    pub fn evacuate(&mut self, floor_to: usize) {
+        self.floor_to = floor_to;
+        self.is_leaving = true;
+    }
+
     /// Sample a person's `dst_tip` distribution to determine whether or not they will
     /// decide to tip.
-    pub fn gen_tip(&self, mut rng: &mut impl Rng) -> bool {
+    pub fn gen_tip(&self, mut rng: &mut dyn RngCore) -> bool {
         self.dst_tip.sample(&mut rng)
     }
 
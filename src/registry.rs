@@ -0,0 +1,166 @@
+//Import standard/imported modules
+use std::collections::HashMap;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+//Import source modules
+use crate::building::Building;
+use crate::controller::{ElevatorController, RandomController, NearestController, QLearningController};
+
+/// # `ControllerParams` struct
+///
+/// A `ControllerParams` holds named numeric parameters used by a `ControllerRegistry`
+/// to build an `ElevatorController`, for instance a controller read from a config file
+/// at runtime.  Parameters not understood by a controller are ignored.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ControllerParams {
+    pub values: HashMap<String, f64>
+}
+
+//Implement the ControllerParams interface
+impl ControllerParams {
+    /// Initialize a new, empty set of controller parameters.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let my_params: ControllerParams = ControllerParams::new()
+    ///     .with("p_rational", 0.5_f64)
+    ///     .with("seed", 42_f64);
+    /// ```
+    pub fn new() -> ControllerParams {
+        ControllerParams {
+            values: HashMap::new()
+        }
+    }
+
+    /// Set the parameter with the given name to the given value, and return the
+    /// updated parameters.
+    pub fn with(mut self, name: &str, value: f64) -> ControllerParams {
+        self.values.insert(String::from(name), value);
+        self
+    }
+
+    /// Get the value of the parameter with the given name, if it is set
+    pub fn get(&self, name: &str) -> Option<f64> {
+        self.values.get(name).copied()
+    }
+
+    /// Get the value of the parameter with the given name, or the given default
+    /// value if it is not set
+    pub fn get_or(&self, name: &str, default: f64) -> f64 {
+        self.get(name).unwrap_or(default)
+    }
+}
+
+/// A `ControllerFactory` builds a boxed `ElevatorController` for the given `Building`
+/// from a set of `ControllerParams`.  The building is only borrowed to size the
+/// controller, since the `Simulator` running the controller owns the building.
+pub type ControllerFactory = Box<dyn Fn(&Building, &ControllerParams) -> Box<dyn ElevatorController>>;
+
+/// # `ControllerRegistry` struct
+///
+/// A `ControllerRegistry` maps controller names to `ControllerFactory` functions, so
+/// that an `ElevatorController` can be picked by name at runtime.  A new registry
+/// knows the built-in `"random"`, `"nearest"`, and `"qlearning"` controllers, and
+/// custom controllers can be registered alongside them.
+pub struct ControllerRegistry {
+    factories: HashMap<String, ControllerFactory>
+}
+
+//Implement the ControllerRegistry interface
+impl ControllerRegistry {
+    /// Initialize a new ControllerRegistry with the built-in controllers registered.
+    /// The `"random"` controller reads the `p_rational` parameter (defaulting to
+    /// `0.0_f64`) and the `seed` parameter (defaulting to a random seed).  The
+    /// `"nearest"` controller takes no parameters.  The `"qlearning"` controller reads
+    /// the `alpha` (defaulting to `0.1_f64`), `gamma` (defaulting to `0.95_f64`),
+    /// `epsilon` (defaulting to `1.0_f64`), `epsilon_min` (defaulting to `0.05_f64`),
+    /// and `seed` parameters.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let my_registry: ControllerRegistry = ControllerRegistry::new();
+    /// let my_building: Building = Building::from(
+    ///     4_usize,
+    ///     2_usize,
+    ///     0.5_f64,
+    ///     5.0_f64,
+    ///     2.5_f64,
+    ///     0.5_f64
+    /// );
+    /// let my_params: ControllerParams = ControllerParams::new().with("p_rational", 0.5_f64);
+    /// let my_controller: Box<dyn ElevatorController> = my_registry.build(
+    ///     "random",
+    ///     &my_building,
+    ///     &my_params
+    /// ).unwrap();
+    /// ```
+    pub fn new() -> ControllerRegistry {
+        //Initialize an empty registry
+        let mut registry: ControllerRegistry = ControllerRegistry {
+            factories: HashMap::new()
+        };
+
+        //Register the built-in controllers
+        registry.register("random", Box::new(|building: &Building, params: &ControllerParams| {
+            let rng: StdRng = match params.get("seed") {
+                Some(seed) => StdRng::seed_from_u64(seed as u64),
+                None => StdRng::from_entropy()
+            };
+            let p_rational: f64 = params.get_or("p_rational", 0.0_f64).clamp(0.0_f64, 1.0_f64);
+            Box::new(RandomController::from(building, rng, p_rational)) as Box<dyn ElevatorController>
+        }));
+        registry.register("nearest", Box::new(|_building: &Building, _params: &ControllerParams| {
+            Box::new(NearestController::new()) as Box<dyn ElevatorController>
+        }));
+        registry.register("qlearning", Box::new(|building: &Building, params: &ControllerParams| {
+            let rng: StdRng = match params.get("seed") {
+                Some(seed) => StdRng::seed_from_u64(seed as u64),
+                None => StdRng::from_entropy()
+            };
+            Box::new(QLearningController::from(
+                building,
+                rng,
+                params.get_or("alpha", 0.1_f64),
+                params.get_or("gamma", 0.95_f64),
+                params.get_or("epsilon", 1.0_f64),
+                params.get_or("epsilon_min", 0.05_f64)
+            )) as Box<dyn ElevatorController>
+        }));
+        registry
+    }
+
+    /// Register a controller factory under the given name, replacing any factory
+    /// already registered under that name.
+    pub fn register(&mut self, name: &str, factory: ControllerFactory) {
+        self.factories.insert(String::from(name), factory);
+    }
+
+    /// Get the names of the registered controllers in alphabetical order
+    pub fn get_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.factories.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// Determine whether a controller is registered under the given name
+    pub fn contains(&self, name: &str) -> bool {
+        self.factories.contains_key(name)
+    }
+
+    /// Build the controller registered under the given name for the given building
+    /// and parameters.  Returns `None` if no controller is registered under the name.
+    pub fn build(&self, name: &str, building: &Building, params: &ControllerParams) -> Option<Box<dyn ElevatorController>> {
+        self.factories.get(name).map(|factory| factory(building, params))
+    }
+}
+
+//Implement the Default trait for the ControllerRegistry
+impl Default for ControllerRegistry {
+    /// Initialize a new ControllerRegistry with the built-in controllers registered
+    fn default() -> Self {
+        ControllerRegistry::new()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,347 @@
+//Import standard/imported modules
+use std::io::{self, BufRead, Write};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Duration;
+use rand::RngCore;
+use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
+
+//Import source modules
+use crate::building::Building;
+use crate::elevator::{Elevator, LoadLevel};
+use crate::floor::Floor;
+use crate::people::People;
+use crate::simulator::Simulator;
+
+//Constant representing the ANSI escape sequence which clears the terminal
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+//Constants defining the bounds of the playback speed, in time steps per second
+const MIN_STEPS_PER_SECOND: f64 = 0.25_f64;
+const MAX_STEPS_PER_SECOND: f64 = 64.0_f64;
+
+/// # `Renderer` struct
+///
+/// A `Renderer` draws a live, colored view of a `Building` to the terminal using
+/// `termcolor`.  Floors are labelled with their destination probability, colored
+/// from blue (cold) to red (hot).  Elevators are drawn in their shafts with their
+/// load and direction, colored green, yellow, or red by how full they are.  The
+/// people waiting on each floor are drawn as a bar, and a panel of metrics is drawn
+/// alongside the building.
+pub struct Renderer {
+    pub bar_width: usize,
+    stream: StandardStream
+}
+
+//Implement the Renderer interface
+impl Renderer {
+    /// Initialize a new Renderer writing to stdout given the `ColorChoice` (from the
+    /// termcolor library) and the maximum width of the waiting bars.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let my_renderer: Renderer = Renderer::from(ColorChoice::Auto, 20_usize);
+    /// ```
+    pub fn from(color_choice: ColorChoice, bar_width: usize) -> Renderer {
+        Renderer {
+            bar_width: bar_width,
+            stream: StandardStream::stdout(color_choice)
+        }
+    }
+
+    /// Get the color of a floor given its destination probability
+    fn get_heat_color(dest_prob: f64) -> Color {
+        if dest_prob < 0.25_f64 {
+            Color::Blue
+        } else if dest_prob < 0.5_f64 {
+            Color::Cyan
+        } else if dest_prob < 0.75_f64 {
+            Color::Yellow
+        } else {
+            Color::Red
+        }
+    }
+
+    /// Get the color of an elevator given how full it is, or magenta if it is out of
+    /// service
+    fn get_load_color(elevator: &Elevator) -> Color {
+        match elevator.get_load_level() {
+            LoadLevel::Light => Color::Green,
+            LoadLevel::Partial => Color::Yellow,
+            LoadLevel::Full => Color::Red,
+            LoadLevel::Unavailable => Color::Magenta
+        }
+    }
+
+    /// Get the symbol representing an elevator's direction: `^` moving up, `v` moving
+    /// down, `-` stopped, `z` asleep, and `x` out of service
+    fn get_direction_symbol(elevator: &Elevator) -> char {
+        if elevator.is_out_of_service() {
+            'x'
+        } else if elevator.sleeping {
+            'z'
+        } else if elevator.stopped {
+            '-'
+        } else if elevator.moving_up {
+            '^'
+        } else {
+            'v'
+        }
+    }
+
+    /// Get the lines of the metrics panel drawn alongside the building
+    fn get_metrics_lines(building: &Building) -> Vec<String> {
+        vec![
+            format!("Time step:      {}", building.time_step),
+            format!("Mode:           {:?}", building.mode),
+            format!("Avg wait time:  {:.2}", building.avg_wait_time),
+            format!("Avg energy:     {:.2}", building.avg_energy),
+            format!("Total tips:     ${:.2}", building.tot_tips),
+            format!("Arrived:        {}", building.tot_arrived),
+            format!("Departed:       {}", building.tot_departed),
+            format!("Rejected:       {}", building.tot_rejected),
+            format!("Abandoned:      {}", building.get_num_abandoned()),
+            format!("In building:    {}", building.get_num_people_in_building())
+        ]
+    }
+
+    /// Write a floor's row of the building, from its label to its waiting bar
+    fn write_floor(&mut self, building: &Building, floor_index: usize, floor: &Floor) -> io::Result<()> {
+        //Write the floor label colored by its destination probability
+        self.stream.set_color(ColorSpec::new().set_fg(Some(Renderer::get_heat_color(floor.dest_prob))).set_bold(true))?;
+        write!(self.stream, "{:>3.3} {:.2}", floor.name, floor.dest_prob)?;
+        self.stream.reset()?;
+        write!(self.stream, " |")?;
+
+        //Write each elevator shaft, drawing the elevators on this floor
+        for elevator in building.elevators.iter() {
+            if elevator.floor_on != floor_index {
+                write!(self.stream, "    :    ")?;
+                continue;
+            }
+            self.stream.set_color(ColorSpec::new().set_fg(Some(Renderer::get_load_color(elevator))).set_bold(true))?;
+            write!(self.stream, "[{:>2}/{:<2} {}]", elevator.get_num_people(), elevator.capacity, Renderer::get_direction_symbol(elevator))?;
+            self.stream.reset()?;
+        }
+        write!(self.stream, "| ")?;
+
+        //Write the waiting bar, truncated to the bar width
+        let num_waiting: usize = floor.get_num_people_waiting();
+        let bar: String = "#".repeat(num_waiting.min(self.bar_width));
+        let overflow: &str = if num_waiting > self.bar_width { "+" } else { "" };
+        self.stream.set_color(ColorSpec::new().set_fg(Some(Color::White)))?;
+        write!(self.stream, "{:<width$}", format!("{}{}", bar, overflow), width = self.bar_width + 1_usize)?;
+        self.stream.reset()?;
+        write!(self.stream, " {:>3}", num_waiting)
+    }
+
+    /// Clear the terminal and draw the building, with the given status line (such as
+    /// the playback state) drawn beneath it.
+    pub fn render(&mut self, building: &Building, status: &str) -> io::Result<()> {
+        //Clear the terminal
+        write!(self.stream, "{}", CLEAR_SCREEN)?;
+
+        //Write the floors from top to bottom, with the metrics panel alongside
+        let metrics_lines: Vec<String> = Renderer::get_metrics_lines(building);
+        let num_floors: usize = building.floors.len();
+        let num_lines: usize = num_floors.max(metrics_lines.len());
+        for line in 0..num_lines {
+            //Write the floor on this line, if any
+            if line < num_floors {
+                let floor_index: usize = num_floors - 1_usize - line;
+                self.write_floor(building, floor_index, &building.floors[floor_index])?;
+            } else {
+                let width: usize = 17_usize + (9_usize * building.elevators.len()) + self.bar_width;
+                write!(self.stream, "{:width$}", "", width = width)?;
+            }
+
+            //Write the metrics panel line, if any
+            match metrics_lines.get(line) {
+                Some(metrics_line) => writeln!(self.stream, "   {}", metrics_line)?,
+                None => writeln!(self.stream)?
+            }
+        }
+
+        //Write the status line and flush
+        writeln!(self.stream)?;
+        writeln!(self.stream, "{}", status)?;
+        self.stream.flush()
+    }
+}
+
+/// # `PlaybackCommand` enum
+///
+/// A `PlaybackCommand` controls a `Player` while it plays a simulation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PlaybackCommand {
+    /// Pause the playback, or resume it if paused
+    TogglePause,
+    /// Advance a single time step while paused
+    Step,
+    /// Double the playback speed
+    SpeedUp,
+    /// Halve the playback speed
+    SlowDown,
+    /// Stop the playback
+    Quit
+}
+
+//Implement the PlaybackCommand interface
+impl PlaybackCommand {
+    /// Parse a playback command from a line of user input: `p` to pause or resume,
+    /// `s` (or an empty line) to single-step, `+` to speed up, `-` to slow down, and
+    /// `q` to quit.  Returns `None` if the input is not a command.
+    pub fn parse(input: &str) -> Option<PlaybackCommand> {
+        match input.trim() {
+            "p" => Some(PlaybackCommand::TogglePause),
+            "s" | "" => Some(PlaybackCommand::Step),
+            "+" | "=" => Some(PlaybackCommand::SpeedUp),
+            "-" | "_" => Some(PlaybackCommand::SlowDown),
+            "q" => Some(PlaybackCommand::Quit),
+            _ => None
+        }
+    }
+}
+
+/// # `Player` struct
+///
+/// A `Player` steps a `Simulator` and redraws its building with a `Renderer` after
+/// every time step, so that controllers can be watched as they behave.  The playback
+/// speed is given in time steps per second, and the playback can be paused and
+/// single-stepped with `PlaybackCommand`s, which are read from stdin one per line
+/// when enabled.
+pub struct Player {
+    pub renderer: Renderer,
+    pub steps_per_second: f64,
+    pub paused: bool,
+    commands: Option<Receiver<PlaybackCommand>>
+}
+
+//Implement the Player interface
+impl Player {
+    /// Initialize a new Player given a `Renderer` and the playback speed in time steps
+    /// per second.  The player starts unpaused and without playback controls.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let my_renderer: Renderer = Renderer::from(ColorChoice::Auto, 20_usize);
+    /// let my_player: Player = Player::from(my_renderer, 4.0_f64);
+    /// ```
+    pub fn from(renderer: Renderer, steps_per_second: f64) -> Player {
+        Player {
+            renderer: renderer,
+            steps_per_second: steps_per_second.clamp(MIN_STEPS_PER_SECOND, MAX_STEPS_PER_SECOND),
+            paused: false,
+            commands: None
+        }
+    }
+
+    /// Enable the playback controls by reading `PlaybackCommand`s from stdin, one per
+    /// line, on a separate thread.
+    pub fn enable_stdin_controls(&mut self) {
+        let (sender, commands) = mpsc::channel::<PlaybackCommand>();
+        thread::spawn(move || {
+            for line in io::stdin().lock().lines() {
+                let Ok(line) = line else { break; };
+                if let Some(command) = PlaybackCommand::parse(&line) {
+                    if sender.send(command).is_err() {
+                        break;
+                    }
+                }
+            }
+        });
+        self.commands = Some(commands);
+    }
+
+    /// Apply a playback command, returning true if the playback should stop
+    fn apply_command(&mut self, command: PlaybackCommand) -> bool {
+        match command {
+            PlaybackCommand::TogglePause => self.paused = !self.paused,
+            PlaybackCommand::Step => {},
+            PlaybackCommand::SpeedUp => {
+                self.steps_per_second = (self.steps_per_second * 2.0_f64).min(MAX_STEPS_PER_SECOND);
+            },
+            PlaybackCommand::SlowDown => {
+                self.steps_per_second = (self.steps_per_second / 2.0_f64).max(MIN_STEPS_PER_SECOND);
+            },
+            PlaybackCommand::Quit => return true
+        }
+        false
+    }
+
+    /// Get the status line describing the playback state
+    fn get_status(&self) -> String {
+        let state: &str = if self.paused { "PAUSED" } else { "PLAYING" };
+        let controls: &str = if self.commands.is_some() {
+            "  [p] pause/resume  [s] step  [+/-] speed  [q] quit"
+        } else {
+            ""
+        };
+        format!("{} at {:.2} steps/s{}", state, self.steps_per_second, controls)
+    }
+
+    /// Play the given number of time steps of the simulation, redrawing
+    /// the building after each one.  Returns early if the playback is quit, and
+    /// returns an error if the terminal cannot be written to or the building violates
+    /// one of its invariants while in debug mode.
+    pub fn play(&mut self, simulator: &mut Simulator, rng: &mut dyn RngCore,
+                num_steps: usize) -> Result<(), Box<dyn std::error::Error>> {
+        //Draw the initial state of the building
+        let status: String = self.get_status();
+        self.renderer.render(&simulator.building, &status)?;
+
+        //Loop through the time steps
+        let mut step: usize = 0_usize;
+        while step < num_steps {
+            //Apply any pending playback commands
+            let mut single_step: bool = false;
+            if let Some(commands) = &self.commands {
+                let pending: Vec<PlaybackCommand> = commands.try_iter().collect();
+                for command in pending {
+                    single_step |= command == PlaybackCommand::Step;
+                    if self.apply_command(command) {
+                        return Ok(());
+                    }
+                }
+            }
+
+            //If paused, then wait for the next playback command unless single-stepping
+            if self.paused && !single_step {
+                let status: String = self.get_status();
+                self.renderer.render(&simulator.building, &status)?;
+                let command: Option<PlaybackCommand> = self.commands.as_ref()
+                    .and_then(|commands| commands.recv().ok());
+                match command {
+                    Some(command) => {
+                        if command == PlaybackCommand::Step {
+                            self.step_and_render(simulator, rng)?;
+                            step += 1_usize;
+                        } else if self.apply_command(command) {
+                            return Ok(());
+                        }
+                    },
+                    None => self.paused = false
+                }
+                continue;
+            }
+
+            //Step the simulation, redraw the building, and wait for the next step
+            self.step_and_render(simulator, rng)?;
+            step += 1_usize;
+            if !self.paused {
+                thread::sleep(Duration::from_secs_f64(1.0_f64 / self.steps_per_second));
+            }
+        }
+        Ok(())
+    }
+
+    /// Step the simulation once and redraw the building
+    fn step_and_render(&mut self, simulator: &mut Simulator, rng: &mut dyn RngCore) -> Result<(), Box<dyn std::error::Error>> {
+        simulator.step(rng)?;
+        let status: String = self.get_status();
+        self.renderer.render(&simulator.building, &status)?;
+        Ok(())
+    }
+}
\ No newline at end of file
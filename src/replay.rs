@@ -0,0 +1,570 @@
+//Import standard/imported modules
+use std::fs;
+use std::path::Path;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+
+//Import source modules
+use crate::building::Building;
+use crate::command::ElevatorCommand;
+use crate::emergency::BuildingMode;
+use crate::scenario::{Scenario, ScenarioConfig};
+use crate::simulator::Simulator;
+use crate::outcome::{OutcomeSource, BoolOutcome, CountOutcome, ValueOutcome};
+use crate::validation::InvariantViolation;
+
+//Constant representing the number of time steps between the checkpoints of a Replay
+const REPLAY_CHECKPOINT_INTERVAL: usize = 100_usize;
+
+//Constants representing the number of kinds of each type of outcome
+const NUM_BOOL_OUTCOMES: usize = 3_usize;
+const NUM_COUNT_OUTCOMES: usize = 6_usize;
+const NUM_VALUE_OUTCOMES: usize = 2_usize;
+
+/// # `StepOutcomes` struct
+///
+/// A `StepOutcomes` holds the random outcomes of a single time step of a run, in the
+/// order the building generated them.  Yes or no outcomes are recorded as the
+/// positions, among the outcomes of their kind during the step, of those which came
+/// true, since most are false.  Empty lists are omitted when serialized, so the
+/// recording stays compact, and it does not depend on how the outcomes were sampled.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StepOutcomes {
+    /// The positions of the elevator checks which found a breakdown
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub breakdowns: Vec<usize>,
+    /// The repair time of each breakdown
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub repair_times: Vec<usize>,
+    /// The number of arrivals through each entrance
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub arrivals: Vec<usize>,
+    /// The entrance of each arrival, when the number of arrivals was given
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub arrival_entrances: Vec<usize>,
+    /// The destination floor of each arrival
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub destinations: Vec<usize>,
+    /// The mass of each arrival, if masses are randomized
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub masses: Vec<f64>,
+    /// The positions of the arrivals who brought along cargo, among those who might
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub cargo: Vec<usize>,
+    /// The patience of each arrival, if people are impatient
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub patiences: Vec<usize>,
+    /// The exit entrance of each arrival, if the building has several entrances
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub exits: Vec<usize>,
+    /// The positions of the people who decided to leave the building, among those
+    /// who might
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub departures: Vec<usize>,
+    /// The total value of the tips left by the people departing the building
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tips: Vec<f64>
+}
+
+//Implement the StepOutcomes interface
+impl StepOutcomes {
+    /// Immutably borrow the positions of the yes or no outcomes of the given kind which
+    /// came true
+    pub fn get_bools(&self, kind: BoolOutcome) -> &Vec<usize> {
+        match kind {
+            BoolOutcome::Breakdown => &self.breakdowns,
+            BoolOutcome::Cargo => &self.cargo,
+            BoolOutcome::Departure => &self.departures
+        }
+    }
+
+    /// Mutably borrow the positions of the yes or no outcomes of the given kind which
+    /// came true
+    fn get_bools_mut(&mut self, kind: BoolOutcome) -> &mut Vec<usize> {
+        match kind {
+            BoolOutcome::Breakdown => &mut self.breakdowns,
+            BoolOutcome::Cargo => &mut self.cargo,
+            BoolOutcome::Departure => &mut self.departures
+        }
+    }
+
+    /// Immutably borrow the count outcomes of the given kind
+    pub fn get_counts(&self, kind: CountOutcome) -> &Vec<usize> {
+        match kind {
+            CountOutcome::RepairTime => &self.repair_times,
+            CountOutcome::NumArrivals => &self.arrivals,
+            CountOutcome::ArrivalEntrance => &self.arrival_entrances,
+            CountOutcome::Destination => &self.destinations,
+            CountOutcome::Patience => &self.patiences,
+            CountOutcome::ExitEntrance => &self.exits
+        }
+    }
+
+    /// Mutably borrow the count outcomes of the given kind
+    fn get_counts_mut(&mut self, kind: CountOutcome) -> &mut Vec<usize> {
+        match kind {
+            CountOutcome::RepairTime => &mut self.repair_times,
+            CountOutcome::NumArrivals => &mut self.arrivals,
+            CountOutcome::ArrivalEntrance => &mut self.arrival_entrances,
+            CountOutcome::Destination => &mut self.destinations,
+            CountOutcome::Patience => &mut self.patiences,
+            CountOutcome::ExitEntrance => &mut self.exits
+        }
+    }
+
+    /// Immutably borrow the real valued outcomes of the given kind
+    pub fn get_values(&self, kind: ValueOutcome) -> &Vec<f64> {
+        match kind {
+            ValueOutcome::Mass => &self.masses,
+            ValueOutcome::Tips => &self.tips
+        }
+    }
+
+    /// Mutably borrow the real valued outcomes of the given kind
+    fn get_values_mut(&mut self, kind: ValueOutcome) -> &mut Vec<f64> {
+        match kind {
+            ValueOutcome::Mass => &mut self.masses,
+            ValueOutcome::Tips => &mut self.tips
+        }
+    }
+}
+
+/// # `RecordingOutcomes` struct
+///
+/// A `RecordingOutcomes` implements the `OutcomeSource` trait.  It samples every
+/// outcome from an rng, as an `RngOutcomes` does, and records it into a
+/// `StepOutcomes`, so that the same outcomes can later be played back by a
+/// `PlaybackOutcomes`.
+pub struct RecordingOutcomes<'a> {
+    pub outcomes: StepOutcomes,
+    num_bools: [usize; NUM_BOOL_OUTCOMES],
+    rng: &'a mut dyn RngCore
+}
+
+//Implement the RecordingOutcomes interface
+impl<'a> RecordingOutcomes<'a> {
+    /// Initialize a new RecordingOutcomes given the rng to sample outcomes from
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let mut my_rng: StdRng = StdRng::seed_from_u64(0_u64);
+    /// let mut my_recording: RecordingOutcomes = RecordingOutcomes::from(&mut my_rng);
+    /// my_simulator.step_with_outcomes(None, &mut my_recording).unwrap();
+    /// let my_outcomes: StepOutcomes = my_recording.outcomes;
+    /// ```
+    pub fn from(rng: &'a mut dyn RngCore) -> RecordingOutcomes<'a> {
+        RecordingOutcomes {
+            outcomes: StepOutcomes::default(),
+            num_bools: [0_usize; NUM_BOOL_OUTCOMES],
+            rng: rng
+        }
+    }
+}
+
+//Implement the OutcomeSource trait for the RecordingOutcomes
+impl OutcomeSource for RecordingOutcomes<'_> {
+    /// Sample the yes or no outcome from the rng, recording its position if it is true
+    fn gen_bool(&mut self, kind: BoolOutcome, sampler: &mut dyn FnMut(&mut dyn RngCore) -> bool) -> bool {
+        let outcome: bool = sampler(self.rng);
+        if outcome {
+            self.outcomes.get_bools_mut(kind).push(self.num_bools[kind as usize]);
+        }
+        self.num_bools[kind as usize] += 1_usize;
+        outcome
+    }
+
+    /// Sample the count outcome from the rng and record it
+    fn gen_count(&mut self, kind: CountOutcome, sampler: &mut dyn FnMut(&mut dyn RngCore) -> usize) -> usize {
+        let outcome: usize = sampler(self.rng);
+        self.outcomes.get_counts_mut(kind).push(outcome);
+        outcome
+    }
+
+    /// Sample the real valued outcome from the rng and record it
+    fn gen_value(&mut self, kind: ValueOutcome, sampler: &mut dyn FnMut(&mut dyn RngCore) -> f64) -> f64 {
+        let outcome: f64 = sampler(self.rng);
+        self.outcomes.get_values_mut(kind).push(outcome);
+        outcome
+    }
+}
+
+/// # `PlaybackOutcomes` struct
+///
+/// A `PlaybackOutcomes` implements the `OutcomeSource` trait.  It returns the outcomes
+/// recorded by a `RecordingOutcomes` in order, without sampling them.  If an outcome
+/// is requested beyond those recorded, then it returns a false, zero, or the default
+/// value and marks itself as diverged, since the run being played back has diverged
+/// from the recording.
+pub struct PlaybackOutcomes<'a> {
+    pub diverged: bool,
+    outcomes: &'a StepOutcomes,
+    num_bools: [usize; NUM_BOOL_OUTCOMES],
+    num_counts: [usize; NUM_COUNT_OUTCOMES],
+    num_values: [usize; NUM_VALUE_OUTCOMES]
+}
+
+//Implement the PlaybackOutcomes interface
+impl<'a> PlaybackOutcomes<'a> {
+    /// Initialize a new PlaybackOutcomes given the recorded outcomes to return
+    pub fn from(outcomes: &'a StepOutcomes) -> PlaybackOutcomes<'a> {
+        PlaybackOutcomes {
+            diverged: false,
+            outcomes: outcomes,
+            num_bools: [0_usize; NUM_BOOL_OUTCOMES],
+            num_counts: [0_usize; NUM_COUNT_OUTCOMES],
+            num_values: [0_usize; NUM_VALUE_OUTCOMES]
+        }
+    }
+
+    /// Return a boolean signifying whether every recorded outcome was returned, and no
+    /// more were requested
+    pub fn is_finished(&self) -> bool {
+        let bools_finished: bool = [BoolOutcome::Breakdown, BoolOutcome::Cargo, BoolOutcome::Departure].iter()
+            .all(|kind| self.outcomes.get_bools(*kind).iter().all(|position| *position < self.num_bools[*kind as usize]));
+        let counts_finished: bool = [
+            CountOutcome::RepairTime,
+            CountOutcome::NumArrivals,
+            CountOutcome::ArrivalEntrance,
+            CountOutcome::Destination,
+            CountOutcome::Patience,
+            CountOutcome::ExitEntrance
+        ].iter().all(|kind| self.outcomes.get_counts(*kind).len() == self.num_counts[*kind as usize]);
+        let values_finished: bool = [ValueOutcome::Mass, ValueOutcome::Tips].iter()
+            .all(|kind| self.outcomes.get_values(*kind).len() == self.num_values[*kind as usize]);
+        !self.diverged && bools_finished && counts_finished && values_finished
+    }
+}
+
+//Implement the OutcomeSource trait for the PlaybackOutcomes
+impl OutcomeSource for PlaybackOutcomes<'_> {
+    /// Return whether the next yes or no outcome of the kind was recorded as true
+    fn gen_bool(&mut self, kind: BoolOutcome, _sampler: &mut dyn FnMut(&mut dyn RngCore) -> bool) -> bool {
+        let position: usize = self.num_bools[kind as usize];
+        self.num_bools[kind as usize] += 1_usize;
+        self.outcomes.get_bools(kind).binary_search(&position).is_ok()
+    }
+
+    /// Return the next recorded count outcome of the kind
+    fn gen_count(&mut self, kind: CountOutcome, _sampler: &mut dyn FnMut(&mut dyn RngCore) -> usize) -> usize {
+        let index: usize = self.num_counts[kind as usize];
+        self.num_counts[kind as usize] += 1_usize;
+        match self.outcomes.get_counts(kind).get(index) {
+            Some(outcome) => *outcome,
+            None => {
+                self.diverged = true;
+                0_usize
+            }
+        }
+    }
+
+    /// Return the next recorded real valued outcome of the kind
+    fn gen_value(&mut self, kind: ValueOutcome, _sampler: &mut dyn FnMut(&mut dyn RngCore) -> f64) -> f64 {
+        let index: usize = self.num_values[kind as usize];
+        self.num_values[kind as usize] += 1_usize;
+        match self.outcomes.get_values(kind).get(index) {
+            Some(outcome) => *outcome,
+            None => {
+                self.diverged = true;
+                0.0_f64
+            }
+        }
+    }
+}
+
+/// # `ReplayStep` struct
+///
+/// A `ReplayStep` records a single time step of a run: its random outcomes, such as
+/// the arrivals, destinations, departures, breakdowns, and tips, and the commands
+/// decided by the controller.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ReplayStep {
+    #[serde(flatten)]
+    pub outcomes: StepOutcomes,
+    pub commands: Vec<ElevatorCommand>
+}
+
+/// # `ReplayLog` struct
+///
+/// A `ReplayLog` records a run of a `Scenario` so that it can be shared and played
+/// back by a `Replay` without the controller which produced it.  It holds the
+/// `ScenarioConfig` the run's building was initialized from, and a `ReplayStep` per
+/// time step.  Runs whose building was modified after being initialized from the
+/// config cannot be played back faithfully.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ReplayLog {
+    pub config: ScenarioConfig,
+    pub steps: Vec<ReplayStep>
+}
+
+//Implement the ReplayLog interface
+impl ReplayLog {
+    /// Initialize a new, empty ReplayLog given the config of the run's building
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let my_config: ScenarioConfig = ScenarioConfig::default();
+    /// let my_scenario: Scenario = Scenario::from_config(&my_config).unwrap();
+    /// let mut my_simulator: Simulator = Simulator::from(
+    ///     my_scenario.building.clone(),
+    ///     Box::new(NearestController::new())
+    /// );
+    /// let mut my_log: ReplayLog = ReplayLog::from(my_config);
+    /// let mut my_rng: StdRng = StdRng::seed_from_u64(0_u64);
+    /// for _ in 0..my_scenario.num_steps {
+    ///     my_log.record_step(&mut my_simulator, &mut my_rng).unwrap();
+    /// }
+    /// ```
+    pub fn from(config: ScenarioConfig) -> ReplayLog {
+        ReplayLog {
+            config: config,
+            steps: Vec::new()
+        }
+    }
+
+    /// Run a single time step of the given simulator, as `Simulator::step` does, while
+    /// recording the step's random outcomes and the commands decided by the
+    /// controller.  The simulator is expected to own a building initialized from the
+    /// log's config, which has only been stepped by this function.
+    pub fn record_step(&mut self, simulator: &mut Simulator, rng: &mut dyn RngCore) -> Result<(), InvariantViolation> {
+        //Step the simulator while recording its outcomes
+        let mut recording: RecordingOutcomes = RecordingOutcomes::from(rng);
+        let result: Result<(), InvariantViolation> = simulator.step_with_outcomes(None, &mut recording);
+
+        //Record the step
+        self.steps.push(ReplayStep {
+            outcomes: recording.outcomes,
+            commands: simulator.last_commands.clone()
+        });
+        result
+    }
+
+    /// Save the log to a JSON file.  Returns an error describing the problem if the
+    /// file cannot be written.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let log_str: String = serde_json::to_string(self)
+            .map_err(|err| format!("cannot encode replay: {}", err))?;
+        fs::write(path, log_str)
+            .map_err(|err| format!("cannot write replay {}: {}", path.display(), err))
+    }
+
+    /// Load a log from a JSON file.  Returns an error describing the problem if the
+    /// file cannot be read or parsed.
+    pub fn load(path: &Path) -> Result<ReplayLog, String> {
+        let log_str: String = fs::read_to_string(path)
+            .map_err(|err| format!("cannot read replay {}: {}", path.display(), err))?;
+        serde_json::from_str(&log_str)
+            .map_err(|err| format!("invalid replay {}: {}", path.display(), err))
+    }
+}
+
+/// # `Replay` struct
+///
+/// A `Replay` plays back a `ReplayLog`, reconstructing the exact sequence of
+/// `Building` states of the recorded run.  It can step forward and backward, and jump
+/// to any time step.  The building is checkpointed every 100 time steps as they are
+/// reached, so that jumping backward only replays the steps since the nearest
+/// checkpoint.  If the log's building is in debug mode, then the invariant violation
+/// found by the last step played, if any, is kept in `last_violation`.
+pub struct Replay {
+    pub log: ReplayLog,
+    pub building: Building,
+    pub last_violation: Option<InvariantViolation>,
+    checkpoints: Vec<Building>
+}
+
+//Implement the Replay interface
+impl Replay {
+    /// Initialize a new Replay given the log to play back, positioned before the first
+    /// time step.  Returns an error describing the problem if the log's config is
+    /// invalid.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let my_log: ReplayLog = ReplayLog::load(Path::new("failing_run.json")).unwrap();
+    /// let mut my_replay: Replay = Replay::from(my_log).unwrap();
+    /// my_replay.seek(250_usize).unwrap();
+    /// my_replay.step_backward().unwrap();
+    /// ```
+    pub fn from(log: ReplayLog) -> Result<Replay, String> {
+        let building: Building = Scenario::from_config(&log.config)?.building;
+        Ok(Replay {
+            log: log,
+            last_violation: None,
+            checkpoints: vec![building.clone()],
+            building: building
+        })
+    }
+
+    /// Get the current time step of the replay, which is the number of steps played
+    pub fn get_step(&self) -> usize {
+        self.building.time_step
+    }
+
+    /// Get the number of time steps in the replay
+    pub fn get_num_steps(&self) -> usize {
+        self.log.steps.len()
+    }
+
+    /// Play the next time step.  Returns false without changing the building if the
+    /// replay is at its last step, and returns an error if the building generated
+    /// different outcomes than were recorded, which means the replay has diverged from
+    /// the recorded run.
+    pub fn step_forward(&mut self) -> Result<bool, String> {
+        //Get the recorded step, if any
+        let step: usize = self.get_step();
+        let replay_step: &ReplayStep = match self.log.steps.get(step) {
+            Some(replay_step) => replay_step,
+            None => return Ok(false)
+        };
+
+        //Begin the step using the recorded outcomes
+        let mut playback: PlaybackOutcomes = PlaybackOutcomes::from(&replay_step.outcomes);
+        self.building.begin_step_with_outcomes(None, &mut playback);
+        if !playback.is_finished() {
+            return Err(format!("replay diverged from the recorded run at step {}", step));
+        }
+
+        //Update the elevators using the recorded commands, then end the step
+        if self.building.mode == BuildingMode::Normal {
+            let _commands_rejected: Vec<ElevatorCommand> = self.building.apply_elevator_commands(&replay_step.commands);
+        } else {
+            self.building.update_elevators_emergency();
+        }
+        self.last_violation = self.building.end_step().err();
+
+        //Checkpoint the building if a new checkpoint was reached
+        let step: usize = self.get_step();
+        if step == self.checkpoints.len() * REPLAY_CHECKPOINT_INTERVAL {
+            self.checkpoints.push(self.building.clone());
+        }
+        Ok(true)
+    }
+
+    /// Return to the previous time step.  Returns false without changing the building
+    /// if the replay is at its first step.
+    pub fn step_backward(&mut self) -> Result<bool, String> {
+        let step: usize = self.get_step();
+        if step == 0_usize {
+            return Ok(false);
+        }
+        self.seek(step - 1_usize)?;
+        Ok(true)
+    }
+
+    /// Jump to the given time step, after that many steps have been played.  Steps
+    /// beyond the end of the replay jump to its last step.
+    pub fn seek(&mut self, step: usize) -> Result<(), String> {
+        //Restore the nearest checkpoint if the step is behind the current step, or if
+        //a checkpoint is closer to it
+        let step: usize = step.min(self.get_num_steps());
+        let checkpoint_index: usize = (step / REPLAY_CHECKPOINT_INTERVAL).min(self.checkpoints.len() - 1_usize);
+        let checkpoint_step: usize = checkpoint_index * REPLAY_CHECKPOINT_INTERVAL;
+        if step < self.get_step() || checkpoint_step > self.get_step() {
+            self.building = self.checkpoints[checkpoint_index].clone();
+            self.last_violation = None;
+        }
+
+        //Play forward to the step
+        while self.get_step() < step {
+            self.step_forward()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use super::*;
+    use crate::controller::NearestController;
+
+    //Summarize the state and metrics of a building, so that runs can be compared
+    fn get_snapshot(building: &Building) -> String {
+        format!(
+            "{} {} {} {} {} {} {} {} {} {} {}",
+            serde_json::to_string(&building.get_view()).unwrap(),
+            building.time_step,
+            building.tot_arrived,
+            building.tot_departed,
+            building.tot_rejected,
+            building.tot_balked,
+            building.tot_reneged,
+            building.tot_breakdowns,
+            building.avg_energy,
+            building.avg_wait_time,
+            building.tot_tips
+        )
+    }
+
+    //Record a run of a busy building which generates every kind of outcome, returning
+    //the log and the snapshot of the building after each step
+    fn record_run(num_steps: usize) -> (ReplayLog, Vec<String>) {
+        let config: ScenarioConfig = ScenarioConfig {
+            num_floors: 8_usize,
+            num_elevators: 2_usize,
+            p_in: 1.5_f64,
+            mtbf: Some(200.0_f64),
+            mass_std_dev: 15.0_f64,
+            p_cargo: 0.1_f64,
+            cargo_mass: 50.0_f64,
+            cargo_volume: 0.5_f64,
+            balk_threshold: Some(8_usize),
+            mean_patience: Some(30.0_f64),
+            max_stairs: 1_usize,
+            entrances: vec![(0_usize, 1.0_f64), (3_usize, 0.5_f64)],
+            debug: true,
+            ..ScenarioConfig::default()
+        };
+        let building: Building = Scenario::from_config(&config).unwrap().building;
+        let mut simulator: Simulator = Simulator::from(building, Box::new(NearestController::new()));
+        let mut log: ReplayLog = ReplayLog::from(config);
+        let mut rng: StdRng = StdRng::seed_from_u64(7_u64);
+        let mut snapshots: Vec<String> = vec![get_snapshot(&simulator.building)];
+        for _ in 0_usize..num_steps {
+            log.record_step(&mut simulator, &mut rng).unwrap();
+            snapshots.push(get_snapshot(&simulator.building));
+        }
+        (log, snapshots)
+    }
+
+    #[test]
+    fn replay_matches_recorded_run_step_for_step() {
+        let (log, snapshots): (ReplayLog, Vec<String>) = record_run(250_usize);
+        let log: ReplayLog = serde_json::from_str(&serde_json::to_string(&log).unwrap()).unwrap();
+        let mut replay: Replay = Replay::from(log).unwrap();
+        assert_eq!(get_snapshot(&replay.building), snapshots[0]);
+        while replay.step_forward().unwrap() {
+            assert_eq!(get_snapshot(&replay.building), snapshots[replay.get_step()]);
+            assert!(replay.last_violation.is_none());
+        }
+        assert_eq!(replay.get_step(), 250_usize);
+    }
+
+    #[test]
+    fn replay_seeks_back_and_forward() {
+        let (log, snapshots): (ReplayLog, Vec<String>) = record_run(250_usize);
+        let mut replay: Replay = Replay::from(log).unwrap();
+        for step in [230_usize, 40_usize, 199_usize, 200_usize, 101_usize, 250_usize, 0_usize, 120_usize] {
+            replay.seek(step).unwrap();
+            assert_eq!(replay.get_step(), step);
+            assert_eq!(get_snapshot(&replay.building), snapshots[step]);
+        }
+        assert!(replay.step_backward().unwrap());
+        assert_eq!(get_snapshot(&replay.building), snapshots[119]);
+        replay.seek(1000_usize).unwrap();
+        assert_eq!(get_snapshot(&replay.building), snapshots[250]);
+    }
+
+    #[test]
+    fn replay_detects_tampered_outcomes() {
+        let (mut log, _): (ReplayLog, Vec<String>) = record_run(20_usize);
+        log.steps[5_usize].outcomes.arrivals.push(1_usize);
+        let mut replay: Replay = Replay::from(log).unwrap();
+        assert!(replay.seek(20_usize).is_err());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,232 @@
+//Import standard/imported modules
+use std::fs;
+use std::path::Path;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use serde::{Deserialize, Serialize};
+
+//Import source modules
+use crate::building::{Building, OverflowPolicy};
+use crate::person::PERSON_MASS;
+use crate::simulator::Simulator;
+use crate::validation::InvariantViolation;
+
+/// # `Scenario` struct
+///
+/// A `Scenario` describes a repeatable set of simulations: the `Building` each run
+/// starts from, the number of time steps per run, and the seeds of the runs.  Running
+/// a scenario with the same controller and seed always gives the same result.
+#[derive(Clone)]
+pub struct Scenario {
+    pub building: Building,
+    pub num_steps: usize,
+    pub seeds: Vec<u64>
+}
+
+//Implement the Scenario interface
+impl Scenario {
+    /// Initialize a new Scenario given the building each run starts from, the number
+    /// of time steps per run, and the seeds of the runs.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let my_building: Building = Building::from(
+    ///     4_usize,
+    ///     2_usize,
+    ///     0.5_f64,
+    ///     5.0_f64,
+    ///     2.5_f64,
+    ///     0.5_f64
+    /// );
+    /// let my_scenario: Scenario = Scenario::from(my_building, 1000_usize, vec![0_u64, 1_u64, 2_u64]);
+    /// ```
+    pub fn from(building: Building, num_steps: usize, seeds: Vec<u64>) -> Scenario {
+        Scenario {
+            building: building,
+            num_steps: num_steps,
+            seeds: seeds
+        }
+    }
+
+    /// Initialize a new Scenario from a `ScenarioConfig`.  Returns an error describing
+    /// the problem if the config is invalid.
+    pub fn from_config(config: &ScenarioConfig) -> Result<Scenario, String> {
+        //Check the parameters which would otherwise panic, ignoring the arrival rate if
+        //the entrances replace it
+        if config.entrances.is_empty() && config.p_in <= 0.0_f64 {
+            return Err(format!("p_in must be positive, got {}", config.p_in));
+        }
+        if config.num_floors == 0_usize {
+            return Err(String::from("num_floors must be positive"));
+        }
+
+        //Initialize the building
+        let mut building: Building = Building::from(
+            config.num_floors,
+            config.num_elevators,
+            config.p_in,
+            config.floor_capacity,
+            config.elevator_capacity,
+            config.energy_up,
+            config.energy_down,
+            config.energy_coef
+        );
+
+        //Update the building's optional behavior
+        building.overflow_policy = match config.overflow_policy.as_str() {
+            "drop" => OverflowPolicy::Drop,
+            "queue" => OverflowPolicy::Queue,
+            "block" => OverflowPolicy::Block,
+            other => return Err(format!("unknown overflow policy {:?}", other))
+        };
+        building.update_reliability(config.mtbf, config.mean_repair_time)?;
+        building.update_arrival_mass(config.mass_mean, config.mass_std_dev)?;
+        building.update_arrival_cargo(config.p_cargo, config.cargo_mass, config.cargo_volume)?;
+        building.update_abandonment(config.balk_threshold, config.mean_patience, config.max_stairs)?;
+        if !config.entrances.is_empty() {
+            if config.entrances.iter().all(|(_, p_in)| *p_in == 0.0_f64) {
+                return Err(String::from("at least one entrance floor must have a positive arrival rate"));
+            }
+            building.update_entrances(&config.entrances)?;
+        }
+        building.update_floor_labels(config.lowest_floor_label);
+        if let Some(forecast_horizon) = config.forecast_horizon {
+            building.forecast_horizon = forecast_horizon;
+        }
+        building.debug = config.debug;
+
+        //Initialize the scenario
+        Ok(Scenario::from(building, config.num_steps, config.seeds.clone()))
+    }
+
+    /// Load a scenario from a JSON file holding a `ScenarioConfig`.  Returns an error
+    /// describing the problem if the file cannot be read or parsed, or if the config
+    /// is invalid.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let my_scenario: Scenario = Scenario::load(Path::new("scenarios/office.json")).unwrap();
+    /// ```
+    pub fn load(path: &Path) -> Result<Scenario, String> {
+        let config: ScenarioConfig = ScenarioConfig::load(path)?;
+        Scenario::from_config(&config)
+    }
+
+    /// Run the scenario's number of time steps on the given simulator, using an rng
+    /// seeded with the given seed.  The simulator is expected to own a clone of the
+    /// scenario's building.  If the building is in debug mode, then the run stops at
+    /// the first invariant violation found, which is returned.
+    pub fn run(&self, simulator: &mut Simulator, seed: u64) -> Result<(), InvariantViolation> {
+        let mut rng: StdRng = StdRng::seed_from_u64(seed);
+        for _ in 0..self.num_steps {
+            simulator.step(&mut rng)?;
+        }
+        Ok(())
+    }
+}
+
+/// # `ScenarioConfig` struct
+///
+/// A `ScenarioConfig` is the serializable description of a `Scenario`, read from JSON
+/// by `Scenario::load`.  Every field is optional and defaults to the value in
+/// `ScenarioConfig::default`.  The `overflow_policy` is one of `"drop"`, `"queue"`, or
+/// `"block"`.  The `entrances` are pairs of an entrance floor's index and its expected
+/// number of arrivals per time step, which replace `p_in`, where the first entrance is
+/// the lobby, and the `lowest_floor_label` labels the bottom floor so that basement
+/// levels can be labelled below zero.  The `forecast_horizon` overrides the number of
+/// time steps the building's destination forecast covers.  The `mass_mean`,
+/// `mass_std_dev`, `p_cargo`, `cargo_mass`, and `cargo_volume` describe the load people
+/// bring, as in `Building::update_arrival_mass` and `Building::update_arrival_cargo`.
+///
+/// ## Example
+///
+/// ```text
+/// {
+///     "num_floors": 10,
+///     "num_elevators": 3,
+///     "p_in": 1.5,
+///     "num_steps": 5000,
+///     "seeds": [0, 1, 2],
+///     "overflow_policy": "queue",
+///     "entrances": [[2, 1.0], [0, 0.5]],
+///     "lowest_floor_label": -2
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ScenarioConfig {
+    pub num_floors: usize,
+    pub num_elevators: usize,
+    pub p_in: f64,
+    pub floor_capacity: usize,
+    pub elevator_capacity: usize,
+    pub energy_up: f64,
+    pub energy_down: f64,
+    pub energy_coef: f64,
+    pub num_steps: usize,
+    pub seeds: Vec<u64>,
+    pub overflow_policy: String,
+    pub mtbf: Option<f64>,
+    pub mean_repair_time: f64,
+    pub mass_mean: f64,
+    pub mass_std_dev: f64,
+    pub p_cargo: f64,
+    pub cargo_mass: f64,
+    pub cargo_volume: f64,
+    pub balk_threshold: Option<usize>,
+    pub mean_patience: Option<f64>,
+    pub max_stairs: usize,
+    pub entrances: Vec<(usize, f64)>,
+    pub lowest_floor_label: i32,
+    pub forecast_horizon: Option<usize>,
+    pub debug: bool
+}
+
+//Implement the ScenarioConfig interface
+impl ScenarioConfig {
+    /// Load a ScenarioConfig from a JSON file.  Returns an error describing the problem
+    /// if the file cannot be read or parsed.
+    pub fn load(path: &Path) -> Result<ScenarioConfig, String> {
+        let config_str: String = fs::read_to_string(path)
+            .map_err(|err| format!("cannot read scenario {}: {}", path.display(), err))?;
+        serde_json::from_str(&config_str)
+            .map_err(|err| format!("invalid scenario {}: {}", path.display(), err))
+    }
+}
+
+//Implement the Default trait for the ScenarioConfig
+impl Default for ScenarioConfig {
+    /// Initialize a ScenarioConfig describing a 4-floor, 2-elevator building run for
+    /// 1000 time steps with a single seed
+    fn default() -> Self {
+        ScenarioConfig {
+            num_floors: 4_usize,
+            num_elevators: 2_usize,
+            p_in: 0.5_f64,
+            floor_capacity: 100_usize,
+            elevator_capacity: 10_usize,
+            energy_up: 5.0_f64,
+            energy_down: 2.5_f64,
+            energy_coef: 0.5_f64,
+            num_steps: 1000_usize,
+            seeds: vec![0_u64],
+            overflow_policy: String::from("drop"),
+            mtbf: None,
+            mean_repair_time: 10.0_f64,
+            mass_mean: PERSON_MASS,
+            mass_std_dev: 0.0_f64,
+            p_cargo: 0.0_f64,
+            cargo_mass: 0.0_f64,
+            cargo_volume: 0.0_f64,
+            balk_threshold: None,
+            mean_patience: None,
+            max_stairs: 0_usize,
+            entrances: Vec::new(),
+            lowest_floor_label: 0_i32,
+            forecast_horizon: None,
+            debug: false
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,198 @@
+//Import standard/imported modules
+use std::fs;
+use std::path::Path;
+use rhai::{Engine, AST, Scope, Dynamic, Array, Map, EvalAltResult, INT};
+
+//Import source modules
+use crate::controller::ElevatorController;
+use crate::view::BuildingView;
+use crate::command::ElevatorCommand;
+
+//Constant representing the maximum number of operations a script may run per decision
+const MAX_SCRIPT_OPERATIONS: u64 = 1_000_000_u64;
+
+/// # `ScriptController` struct
+///
+/// A `ScriptController` implements the `ElevatorController` trait.  It loads its
+/// decision logic from a Rhai script, so that dispatching rules can be tried without
+/// writing Rust.  The script must define a `decide(view)` function, which is called
+/// every time step with a read-only map describing the building, and returns an array
+/// holding the target floor of each elevator.  Targets which are not integers, or are
+/// beyond the building, hold the elevator stopped on its current floor.
+///
+/// The view map holds the following keys:
+///
+/// - `time_step`: the current time step
+/// - `num_floors`: the number of floors in the building
+/// - `cars`: an array of maps, one per elevator, holding its `floor`, `moving_up`,
+///   `stopped`, `available`, `num_people`, `capacity`, `load` (in kg), and
+///   `car_calls` (the destination floors of the people on board)
+/// - `floors`: an array of maps, one per floor, holding its `num_people`,
+///   `num_waiting`, `hall_call_up`, `hall_call_down`, `dest_prob`, and
+///   `dest_forecast` (the probability it becomes a destination floor within the
+///   building's forecast horizon)
+/// - `waiting_floors`: the floors with people waiting
+/// - `dest_floors`: the destination floors across all elevators
+///
+/// ## Example script
+///
+/// ```text
+/// fn decide(view) {
+///     let targets = [];
+///     for car in view.cars {
+///         if car.car_calls.len() > 0 {
+///             targets.push(car.car_calls[0]);
+///         } else if view.waiting_floors.len() > 0 {
+///             targets.push(view.waiting_floors[0]);
+///         } else {
+///             targets.push(car.floor);
+///         }
+///     }
+///     targets
+/// }
+/// ```
+pub struct ScriptController {
+    pub last_error: Option<String>,
+    engine: Engine,
+    ast: AST
+}
+
+//Implement the ScriptController interface
+impl ScriptController {
+    /// Initialize a new ScriptController given the source of a Rhai script.  Returns
+    /// an error if the script cannot be compiled.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let my_script: &str = "fn decide(view) { view.cars.map(|car| 0) }";
+    /// let my_controller: ScriptController = ScriptController::from(my_script).unwrap();
+    /// ```
+    pub fn from(script: &str) -> Result<ScriptController, Box<EvalAltResult>> {
+        //Initialize the scripting engine, limiting the operations per decision
+        let mut engine: Engine = Engine::new();
+        engine.set_max_operations(MAX_SCRIPT_OPERATIONS);
+
+        //Compile the script
+        let ast: AST = engine.compile(script)?;
+
+        //Initialize the controller
+        Ok(ScriptController {
+            last_error: None,
+            engine: engine,
+            ast: ast
+        })
+    }
+
+    /// Initialize a new ScriptController given the path to a Rhai script file.
+    /// Returns an error if the file cannot be read or the script cannot be compiled.
+    pub fn from_file(path: &Path) -> Result<ScriptController, Box<EvalAltResult>> {
+        let script: String = fs::read_to_string(path)
+            .map_err(|err| format!("cannot read script {}: {}", path.display(), err))?;
+        ScriptController::from(&script)
+    }
+
+    /// Convert a view of the building into the map passed to the script
+    fn get_view_map(view: &BuildingView) -> Map {
+        //Convert the elevators into maps
+        let mut cars: Array = Array::new();
+        let mut dest_floors: Array = Array::new();
+        for elevator in view.elevators.iter() {
+            let car_calls: Array = elevator.car_calls.iter().map(|floor| Dynamic::from(*floor as INT)).collect();
+            dest_floors.extend(car_calls.iter().cloned());
+            let mut car: Map = Map::new();
+            car.insert("floor".into(), Dynamic::from(elevator.floor_on as INT));
+            car.insert("moving_up".into(), Dynamic::from(elevator.moving_up));
+            car.insert("stopped".into(), Dynamic::from(elevator.stopped));
+            car.insert("available".into(), Dynamic::from(elevator.is_available()));
+            car.insert("num_people".into(), Dynamic::from(elevator.num_people as INT));
+            car.insert("capacity".into(), Dynamic::from(elevator.capacity as INT));
+            car.insert("load".into(), Dynamic::from(elevator.load));
+            car.insert("car_calls".into(), Dynamic::from(car_calls));
+            cars.push(Dynamic::from(car));
+        }
+
+        //Convert the floors into maps
+        let mut floors: Array = Array::new();
+        let mut waiting_floors: Array = Array::new();
+        for (i, floor_view) in view.floors.iter().enumerate() {
+            if floor_view.are_people_waiting() {
+                waiting_floors.push(Dynamic::from(i as INT));
+            }
+            let mut floor: Map = Map::new();
+            floor.insert("num_people".into(), Dynamic::from(floor_view.num_people as INT));
+            floor.insert("num_waiting".into(), Dynamic::from(floor_view.num_people_waiting as INT));
+            floor.insert("hall_call_up".into(), Dynamic::from(floor_view.hall_call_up));
+            floor.insert("hall_call_down".into(), Dynamic::from(floor_view.hall_call_down));
+            floor.insert("dest_prob".into(), Dynamic::from(floor_view.dest_prob));
+            floor.insert("dest_forecast".into(), Dynamic::from(view.dest_forecast.get(i).copied().unwrap_or(0_f64)));
+            floors.push(Dynamic::from(floor));
+        }
+
+        //Combine them into the view map
+        let mut view_map: Map = Map::new();
+        view_map.insert("time_step".into(), Dynamic::from(view.time_step as INT));
+        view_map.insert("num_floors".into(), Dynamic::from(view.get_num_floors() as INT));
+        view_map.insert("cars".into(), Dynamic::from(cars));
+        view_map.insert("floors".into(), Dynamic::from(floors));
+        view_map.insert("waiting_floors".into(), Dynamic::from(waiting_floors));
+        view_map.insert("dest_floors".into(), Dynamic::from(dest_floors));
+        view_map
+    }
+}
+
+//Implement the ElevatorController trait for the ScriptController
+impl ElevatorController for ScriptController {
+    /// Return a boolean signifying whether the controller can be upgraded or
+    /// not.  Always returns false, since the ScriptController cannot be
+    /// upgraded.
+    fn can_be_upgraded(&self) -> bool {
+        false
+    }
+
+    /// Upgrade the controller given an incrementation float.  Does nothing for
+    /// the ScriptController since it cannot be upgraded.
+    fn upgrade(&mut self, _incrementation: f64) {}
+
+    /// Call the script's `decide` function with a map of the view, and command each
+    /// available elevator toward the target floor returned for it.  If the script
+    /// fails, then the error is stored in `last_error` and every elevator is held on
+    /// its current floor.
+    fn decide(&mut self, view: &BuildingView) -> Vec<ElevatorCommand> {
+        //Call the script's decide function
+        let mut scope: Scope = Scope::new();
+        let view_map: Map = ScriptController::get_view_map(view);
+        let targets: Array = match self.engine.call_fn::<Array>(&mut scope, &self.ast, "decide", (view_map,)) {
+            Ok(targets) => {
+                self.last_error = None;
+                targets
+            },
+            Err(err) => {
+                self.last_error = Some(err.to_string());
+                return Vec::new();
+            }
+        };
+
+        //Loop through the elevators and command them toward their target floors
+        let mut commands: Vec<ElevatorCommand> = Vec::new();
+        for (i, elevator) in view.elevators.iter().enumerate() {
+            //Skip elevators which are unavailable
+            if !elevator.is_available() {
+                continue;
+            }
+
+            //Get the elevator's target floor, holding it if the target is invalid
+            let target: Option<usize> = targets.get(i)
+                .and_then(|target| target.as_int().ok())
+                .and_then(|target| usize::try_from(target).ok())
+                .filter(|target| *target < view.get_num_floors());
+            commands.push(match target {
+                Some(floor_index) => ElevatorCommand::GoToFloor { elevator_index: i, floor_index: floor_index },
+                None => ElevatorCommand::Stop { elevator_index: i }
+            });
+        }
+
+        //Return the commands
+        commands
+    }
+}
\ No newline at end of file
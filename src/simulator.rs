@@ -0,0 +1,126 @@
+//Import standard/imported modules
+use rand::RngCore;
+
+//Import source modules
+use crate::building::Building;
+use crate::controller::ElevatorController;
+use crate::command::ElevatorCommand;
+use crate::emergency::BuildingMode;
+use crate::outcome::{OutcomeSource, RngOutcomes};
+use crate::view::BuildingView;
+use crate::validation::InvariantViolation;
+
+/// # `Simulator` struct
+///
+/// A `Simulator` runs a `Building` under the control of an `ElevatorController`.  The
+/// simulator owns the building, and each time step it hands the controller an
+/// immutable `BuildingView`, then validates and applies the `ElevatorCommand`s the
+/// controller decides.  The controller never has access to the building itself, so it
+/// cannot bypass the commands.  The building remains public so that the owner of the
+/// simulator can inspect it, or change it between time steps, for instance to start
+/// an emergency.
+pub struct Simulator {
+    pub building: Building,
+    pub last_commands: Vec<ElevatorCommand>,
+    pub last_commands_rejected: Vec<ElevatorCommand>,
+    controller: Box<dyn ElevatorController>
+}
+
+//Implement the Simulator interface
+impl Simulator {
+    /// Initialize a new Simulator given the building to simulate and the controller
+    /// of its elevators.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let my_building: Building = Building::from(
+    ///     4_usize,
+    ///     2_usize,
+    ///     0.5_f64,
+    ///     5.0_f64,
+    ///     2.5_f64,
+    ///     0.5_f64
+    /// );
+    /// let my_simulator: Simulator = Simulator::from(
+    ///     my_building,
+    ///     Box::new(NearestController::new())
+    /// );
+    /// ```
+    pub fn from(building: Building, controller: Box<dyn ElevatorController>) -> Simulator {
+        Simulator {
+            building: building,
+            last_commands: Vec::new(),
+            last_commands_rejected: Vec::new(),
+            controller: controller
+        }
+    }
+
+    /// Immutably borrow the controller of the building's elevators
+    pub fn get_controller(&self) -> &dyn ElevatorController {
+        self.controller.as_ref()
+    }
+
+    /// Mutably borrow the controller of the building's elevators, for instance to
+    /// upgrade it
+    pub fn get_controller_mut(&mut self) -> &mut dyn ElevatorController {
+        self.controller.as_mut()
+    }
+
+    /// Swap the controller of the building's elevators for the given one, returning
+    /// the previous controller
+    pub fn set_controller(&mut self, controller: Box<dyn ElevatorController>) -> Box<dyn ElevatorController> {
+        std::mem::replace(&mut self.controller, controller)
+    }
+
+    /// Update the elevators by taking a view of the building, letting the controller
+    /// decide the elevator commands from it, and applying them to the building.  The
+    /// commands decided and the commands rejected by the building as invalid are kept
+    /// in `last_commands` and `last_commands_rejected`.
+    pub fn update_elevators(&mut self) {
+        let view: BuildingView = self.building.get_view();
+        self.last_commands = self.controller.decide(&view);
+        self.last_commands_rejected = self.building.apply_elevator_commands(&self.last_commands);
+    }
+
+    /// Run a single time step of the simulation: begin the step on the building, update
+    /// the elevators, then end the step on the building.  If the building is in an
+    /// emergency mode, then the building updates its own elevators instead, and no
+    /// commands are decided.  If the building is in debug mode, then the first
+    /// invariant violation found after the step is returned.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let mut my_rng: StdRng = StdRng::seed_from_u64(0_u64);
+    /// for _ in 0..1000_usize {
+    ///     my_simulator.step(&mut my_rng).unwrap();
+    /// }
+    /// ```
+    pub fn step(&mut self, rng: &mut dyn RngCore) -> Result<(), InvariantViolation> {
+        self.step_with_arrivals(None, rng)
+    }
+
+    /// Run a single time step of the simulation as `step` does, except that if a
+    /// number of arrivals is given, then it is split across the building's entrances
+    /// instead of sampling each entrance's arrivals.  Used by a `Campus` to share its
+    /// arrivals across its buildings.
+    pub fn step_with_arrivals(&mut self, num_arrivals: Option<usize>, rng: &mut dyn RngCore) -> Result<(), InvariantViolation> {
+        self.step_with_outcomes(num_arrivals, &mut RngOutcomes::from(rng))
+    }
+
+    /// Run a single time step of the simulation as `step_with_arrivals` does, except
+    /// that the random outcomes of the step are decided by the given `OutcomeSource`,
+    /// such as when a `ReplayLog` records them.
+    pub fn step_with_outcomes(&mut self, num_arrivals: Option<usize>, outcomes: &mut dyn OutcomeSource) -> Result<(), InvariantViolation> {
+        self.building.begin_step_with_outcomes(num_arrivals, outcomes);
+        if self.building.mode == BuildingMode::Normal {
+            self.update_elevators();
+        } else {
+            self.building.update_elevators_emergency();
+            self.last_commands.clear();
+            self.last_commands_rejected.clear();
+        }
+        self.building.end_step()
+    }
+}
\ No newline at end of file
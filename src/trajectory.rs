@@ -0,0 +1,246 @@
+//Import standard/imported modules
+use std::fmt::Write as FmtWrite;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use serde::Serialize;
+
+//Import source modules
+use crate::building::Building;
+use crate::export::escape_markup;
+use crate::people::People;
+
+//Constants defining the layout of the trajectory diagram, in pixels
+const TRAJECTORY_MARGIN: f64 = 40.0_f64;
+const TRAJECTORY_WIDTH: f64 = 800.0_f64;
+const TRAJECTORY_FLOOR_HEIGHT: f64 = 24.0_f64;
+
+//Constant defining the colors of the elevators' trajectories, reused cyclically
+const TRAJECTORY_COLORS: [&str; 6] = ["#1565c0", "#c62828", "#2e7d32", "#ef6c00", "#6a1b9a", "#00838f"];
+
+/// # `TrajectoryPoint` struct
+///
+/// A `TrajectoryPoint` holds the state of a single elevator after a single time step:
+/// its floor, whether it is stopped, its number of people and load (in kg), and the
+/// number of people who boarded and got off during the time step.  People are
+/// exchanged at the start of a time step, before the elevator moves, so the exchange
+/// happened on the floor of the elevator's previous point.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct TrajectoryPoint {
+    pub time_step: usize,
+    pub floor_on: usize,
+    pub stopped: bool,
+    pub num_people: usize,
+    pub load: f64,
+    pub num_boarded: usize,
+    pub num_alighted: usize
+}
+
+/// # `TrajectoryRecorder` struct
+///
+/// A `TrajectoryRecorder` samples the trajectory of each of a building's elevators,
+/// one `TrajectoryPoint` per elevator per time step, and exports them as a
+/// floor-versus-time space-time diagram in SVG, or as CSV or JSON data for plotting.
+/// In the diagram each elevator is drawn as a line, solid while carrying people and
+/// dashed while running empty, with a dot where it is stopped, and with a triangle
+/// pointing up where people boarded and pointing down where people got off.  This
+/// makes inefficiencies like bunched elevators and empty runs visible at a glance.
+pub struct TrajectoryRecorder {
+    pub trajectories: Vec<Vec<TrajectoryPoint>>,
+    floor_names: Vec<String>,
+    num_floors: usize
+}
+
+//Implement the TrajectoryRecorder interface
+impl TrajectoryRecorder {
+    /// Initialize a new TrajectoryRecorder given the building it will sample.  The
+    /// number of elevators recorded is fixed by the building, so the recorder should
+    /// only sample that building.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let my_building: Building = Building::from(
+    ///     4_usize,
+    ///     2_usize,
+    ///     0.5_f64,
+    ///     5.0_f64,
+    ///     2.5_f64,
+    ///     0.5_f64
+    /// );
+    /// let my_recorder: TrajectoryRecorder = TrajectoryRecorder::from(&my_building);
+    /// ```
+    pub fn from(building: &Building) -> TrajectoryRecorder {
+        TrajectoryRecorder {
+            trajectories: vec![Vec::new(); building.elevators.len()],
+            floor_names: building.floors.iter().map(|floor| floor.name.clone()).collect(),
+            num_floors: building.floors.len()
+        }
+    }
+
+    /// Get the number of time steps sampled
+    pub fn get_num_samples(&self) -> usize {
+        self.trajectories.first().map_or(0_usize, |trajectory| trajectory.len())
+    }
+
+    /// Sample the point of each of the building's elevators.  Generally called once
+    /// after each time step.
+    pub fn sample(&mut self, building: &Building) {
+        for (trajectory, elevator) in self.trajectories.iter_mut().zip(building.elevators.iter()) {
+            trajectory.push(TrajectoryPoint {
+                time_step: building.time_step,
+                floor_on: elevator.floor_on,
+                stopped: elevator.stopped,
+                num_people: elevator.get_num_people(),
+                load: elevator.get_load(),
+                num_boarded: elevator.num_boarded,
+                num_alighted: elevator.num_alighted
+            });
+        }
+    }
+
+    /// Write the sampled points as CSV, one row per elevator per time step
+    pub fn write_csv(&self, writer: &mut dyn Write) -> io::Result<()> {
+        writeln!(writer, "elevator,step,floor,stopped,num_people,load,boarded,alighted")?;
+        for (i, trajectory) in self.trajectories.iter().enumerate() {
+            for point in trajectory.iter() {
+                writeln!(
+                    writer,
+                    "{},{},{},{},{},{},{},{}",
+                    i,
+                    point.time_step,
+                    point.floor_on,
+                    point.stopped,
+                    point.num_people,
+                    point.load,
+                    point.num_boarded,
+                    point.num_alighted
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Write the sampled points as JSON, as an array holding the array of points of
+    /// each elevator
+    pub fn write_json(&self, writer: &mut dyn Write) -> io::Result<()> {
+        serde_json::to_writer(&mut *writer, &self.trajectories)?;
+        writeln!(writer)
+    }
+
+    /// Get the y coordinate of a floor in the diagram
+    fn get_floor_y(&self, floor_index: usize) -> f64 {
+        TRAJECTORY_MARGIN + (self.num_floors.saturating_sub(floor_index + 1_usize)) as f64 * TRAJECTORY_FLOOR_HEIGHT
+    }
+
+    /// Export the sampled points as a floor-versus-time SVG diagram
+    pub fn to_svg(&self) -> String {
+        //Calculate the dimensions of the diagram
+        let num_samples: usize = self.get_num_samples();
+        let x_step: f64 = TRAJECTORY_WIDTH / num_samples.max(2_usize).saturating_sub(1_usize) as f64;
+        let plot_height: f64 = self.num_floors.saturating_sub(1_usize) as f64 * TRAJECTORY_FLOOR_HEIGHT;
+        let width: f64 = TRAJECTORY_WIDTH + TRAJECTORY_MARGIN * 2.0_f64;
+        let height: f64 = plot_height + TRAJECTORY_MARGIN * 2.0_f64 + self.trajectories.len() as f64 * 14.0_f64;
+        let mut svg: String = String::new();
+        let _ = writeln!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{:.0}\" height=\"{:.0}\" viewBox=\"0 0 {:.0} {:.0}\" font-family=\"monospace\" font-size=\"11\">",
+            width, height, width, height
+        );
+        let _ = writeln!(svg, "<rect width=\"100%\" height=\"100%\" fill=\"#ffffff\"/>");
+
+        //Draw a grid line and label per floor, and label the time axis
+        for floor_index in 0..self.num_floors {
+            let y: f64 = self.get_floor_y(floor_index);
+            let _ = writeln!(
+                svg,
+                "<line x1=\"{:.1}\" y1=\"{:.1}\" x2=\"{:.1}\" y2=\"{:.1}\" stroke=\"#e0e0e0\"/>",
+                TRAJECTORY_MARGIN, y, TRAJECTORY_MARGIN + TRAJECTORY_WIDTH, y
+            );
+            let _ = writeln!(
+                svg,
+                "<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"end\" dominant-baseline=\"middle\">{}</text>",
+                TRAJECTORY_MARGIN - 6.0_f64, y, escape_markup(&self.floor_names[floor_index])
+            );
+        }
+        if let (Some(first), Some(last)) = (
+            self.trajectories.first().and_then(|trajectory| trajectory.first()),
+            self.trajectories.first().and_then(|trajectory| trajectory.last())
+        ) {
+            let axis_y: f64 = TRAJECTORY_MARGIN + plot_height + 16.0_f64;
+            let _ = writeln!(svg, "<text x=\"{:.1}\" y=\"{:.1}\">{}</text>", TRAJECTORY_MARGIN, axis_y, first.time_step);
+            let _ = writeln!(
+                svg,
+                "<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"end\">{}</text>",
+                TRAJECTORY_MARGIN + TRAJECTORY_WIDTH, axis_y, last.time_step
+            );
+        }
+
+        //Draw each elevator's trajectory
+        for (i, trajectory) in self.trajectories.iter().enumerate() {
+            let color: &str = TRAJECTORY_COLORS[i % TRAJECTORY_COLORS.len()];
+            for (j, point) in trajectory.iter().enumerate() {
+                let x: f64 = TRAJECTORY_MARGIN + j as f64 * x_step;
+                let y: f64 = self.get_floor_y(point.floor_on);
+
+                //Draw the segment from the previous point, dashed if running empty,
+                //and mark the people exchanged on the previous point's floor
+                if j > 0_usize {
+                    let prev_x: f64 = x - x_step;
+                    let prev_y: f64 = self.get_floor_y(trajectory[j - 1_usize].floor_on);
+                    let dash: &str = if point.num_people == 0_usize && point.floor_on != trajectory[j - 1_usize].floor_on {
+                        " stroke-dasharray=\"4 3\""
+                    } else {
+                        ""
+                    };
+                    let _ = writeln!(
+                        svg,
+                        "<line x1=\"{:.1}\" y1=\"{:.1}\" x2=\"{:.1}\" y2=\"{:.1}\" stroke=\"{}\" stroke-width=\"1.5\"{}/>",
+                        prev_x, prev_y, x, y, color, dash
+                    );
+                    if point.num_boarded > 0_usize {
+                        let _ = writeln!(
+                            svg,
+                            "<path d=\"M{:.1},{:.1} l4,7 l-8,0 z\" fill=\"{}\"><title>step {}: {} boarded</title></path>",
+                            prev_x, prev_y - 9.0_f64, color, point.time_step, point.num_boarded
+                        );
+                    }
+                    if point.num_alighted > 0_usize {
+                        let _ = writeln!(
+                            svg,
+                            "<path d=\"M{:.1},{:.1} l4,-7 l-8,0 z\" fill=\"none\" stroke=\"{}\"><title>step {}: {} got off</title></path>",
+                            prev_x, prev_y + 9.0_f64, color, point.time_step, point.num_alighted
+                        );
+                    }
+                }
+
+                //Mark the point if the elevator is stopped
+                if point.stopped {
+                    let _ = writeln!(svg, "<circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"1.5\" fill=\"{}\"/>", x, y, color);
+                }
+            }
+
+            //Draw the elevator's legend entry
+            let legend_y: f64 = TRAJECTORY_MARGIN + plot_height + 32.0_f64 + i as f64 * 14.0_f64;
+            let _ = writeln!(
+                svg,
+                "<text x=\"{:.1}\" y=\"{:.1}\" fill=\"{}\">elevator {}</text>",
+                TRAJECTORY_MARGIN, legend_y, color, i
+            );
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Save the sampled points to a file, as CSV if the path ends in `.csv`, as JSON
+    /// if it ends in `.json`, and as an SVG diagram otherwise
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut writer: BufWriter<File> = BufWriter::new(File::create(path)?);
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("csv") => self.write_csv(&mut writer)?,
+            Some("json") => self.write_json(&mut writer)?,
+            _ => writer.write_all(self.to_svg().as_bytes())?
+        }
+        writer.flush()
+    }
+}
\ No newline at end of file
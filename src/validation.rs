@@ -0,0 +1,162 @@
+//Import standard/imported modules
+use std::fmt;
+
+/// # `ViolationKind` enum
+///
+/// A `ViolationKind` describes a structural invariant of a `Building` which does not
+/// hold, along with the context needed to track down the cause.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ViolationKind {
+    /// An elevator is on a floor index at or beyond the number of floors
+    ElevatorOutOfBounds { elevator_index: usize, floor_on: usize, num_floors: usize },
+    /// An elevator carries more people than its capacity
+    ElevatorOverCapacity { elevator_index: usize, num_people: usize, capacity: usize },
+    /// An elevator carries more load (in kg) than its rated load
+    ElevatorOverLoad { elevator_index: usize, load: f64, rated_load: f64 },
+    /// A person on an elevator is not on the same floor as the elevator
+    ElevatorPersonMismatch { elevator_index: usize, person_index: usize, person_floor_on: usize, elevator_floor_on: usize },
+    /// A floor holds more people than its capacity
+    FloorOverCapacity { floor_index: usize, num_people: usize, capacity: usize },
+    /// A person on a floor is not on the floor they are held by
+    FloorPersonMismatch { floor_index: usize, person_index: usize, person_floor_on: usize },
+    /// A person's destination floor is at or beyond the number of floors
+    DestinationOutOfBounds { floor_index: usize, person_index: usize, floor_to: usize, num_floors: usize },
+    /// The people in the building, queueing outside it, departed, rejected, and balked
+    /// do not add up to the people who arrived
+    PeopleNotConserved { num_accounted: usize, num_arrived: usize }
+}
+
+//Display trait implementation for a violation kind
+impl fmt::Display for ViolationKind {
+    /// Format a `ViolationKind` as a string describing the violation and its context.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ViolationKind::ElevatorOutOfBounds { elevator_index, floor_on, num_floors } => write!(
+                f, "elevator {} is on floor {} but the building has {} floors",
+                elevator_index, floor_on, num_floors
+            ),
+            ViolationKind::ElevatorOverCapacity { elevator_index, num_people, capacity } => write!(
+                f, "elevator {} carries {} people but its capacity is {}",
+                elevator_index, num_people, capacity
+            ),
+            ViolationKind::ElevatorOverLoad { elevator_index, load, rated_load } => write!(
+                f, "elevator {} carries {:.2} kg but its rated load is {:.2} kg",
+                elevator_index, load, rated_load
+            ),
+            ViolationKind::ElevatorPersonMismatch { elevator_index, person_index, person_floor_on, elevator_floor_on } => write!(
+                f, "person {} on elevator {} is on floor {} but the elevator is on floor {}",
+                person_index, elevator_index, person_floor_on, elevator_floor_on
+            ),
+            ViolationKind::FloorOverCapacity { floor_index, num_people, capacity } => write!(
+                f, "floor {} holds {} people but its capacity is {}",
+                floor_index, num_people, capacity
+            ),
+            ViolationKind::FloorPersonMismatch { floor_index, person_index, person_floor_on } => write!(
+                f, "person {} held by floor {} is on floor {}",
+                person_index, floor_index, person_floor_on
+            ),
+            ViolationKind::DestinationOutOfBounds { floor_index, person_index, floor_to, num_floors } => write!(
+                f, "person {} on floor {} is going to floor {} but the building has {} floors",
+                person_index, floor_index, floor_to, num_floors
+            ),
+            ViolationKind::PeopleNotConserved { num_accounted, num_arrived } => write!(
+                f, "{} people are accounted for but {} people arrived",
+                num_accounted, num_arrived
+            )
+        }
+    }
+}
+
+/// # `InvariantViolation` struct
+///
+/// An `InvariantViolation` is returned when a `Building` is found in an impossible
+/// state.  It holds the time step at which the violation was found along with the
+/// kind of violation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InvariantViolation {
+    pub time_step: usize,
+    pub kind: ViolationKind
+}
+
+//Display trait implementation for an invariant violation
+impl fmt::Display for InvariantViolation {
+    /// Format an `InvariantViolation` as a string.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// println!("{}", my_violation);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invariant violated at time step {}: {}", self.time_step, self.kind)
+    }
+}
+
+//Error trait implementation for an invariant violation
+impl std::error::Error for InvariantViolation {}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use super::*;
+    use crate::building::Building;
+    use crate::person::Person;
+
+    //Initialize a building which has been run for a few time steps
+    fn new_building() -> Building {
+        let mut building: Building = Building::from(
+            5_usize,
+            2_usize,
+            1.0_f64,
+            100_usize,
+            10_usize,
+            5.0_f64,
+            2.5_f64,
+            0.5_f64
+        );
+        let mut rng: StdRng = StdRng::seed_from_u64(0_u64);
+        for _ in 0_usize..10_usize {
+            building.begin_step(&mut rng);
+            building.end_step().unwrap();
+        }
+        building
+    }
+
+    #[test]
+    fn valid_building_passes() {
+        assert_eq!(new_building().validate(), Ok(()));
+    }
+
+    #[test]
+    fn elevator_out_of_bounds_is_flagged() {
+        let mut building: Building = new_building();
+        building.elevators[1].floor_on = 5_usize;
+        assert_eq!(
+            building.validate().unwrap_err().kind,
+            ViolationKind::ElevatorOutOfBounds { elevator_index: 1_usize, floor_on: 5_usize, num_floors: 5_usize }
+        );
+    }
+
+    #[test]
+    fn person_on_wrong_floor_is_flagged() {
+        let mut building: Building = new_building();
+        let mut pers: Person = Person::from_trip(0.0_f64, 0.0_f64, 3_usize, 75.0_f64, 0.0_f64);
+        pers.floor_on = 2_usize;
+        building.elevators[0].people.push(pers);
+        assert!(matches!(
+            building.validate().unwrap_err().kind,
+            ViolationKind::ElevatorPersonMismatch { elevator_index: 0_usize, person_floor_on: 2_usize, .. }
+        ));
+    }
+
+    #[test]
+    fn people_not_conserved_is_flagged_in_debug_mode() {
+        let mut building: Building = new_building();
+        building.debug = true;
+        building.tot_arrived += 1_usize;
+        let violation: InvariantViolation = building.end_step().unwrap_err();
+        assert_eq!(violation.time_step, 11_usize);
+        assert!(matches!(violation.kind, ViolationKind::PeopleNotConserved { .. }));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,293 @@
+//Import standard/imported modules
+use serde::Serialize;
+
+//Import source modules
+use crate::building::Building;
+use crate::elevator::{Elevator, ElevatorStatus};
+use crate::floor::Floor;
+use crate::people::People;
+use crate::emergency::BuildingMode;
+use crate::entrance::Entrance;
+
+/// # `ElevatorView` struct
+///
+/// An `ElevatorView` is a read-only snapshot of an `Elevator`, as observed by an
+/// `ElevatorController` at the start of its decision.  It holds the elevator's
+/// position, direction, load, and car calls, but not the people on board.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ElevatorView {
+    pub floor_on: usize,
+    pub moving_up: bool,
+    pub stopped: bool,
+    pub num_people: usize,
+    pub capacity: usize,
+    pub load: f64,
+    pub rated_load: f64,
+    pub car_calls: Vec<usize>,
+    pub status: ElevatorStatus,
+    pub sleeping: bool,
+    pub idle_steps: usize
+}
+
+/// # `ElevatorView` type implementation
+///
+/// The following functions are used by `ElevatorController` implementations to
+/// inspect an elevator when deciding its commands.
+impl ElevatorView {
+    /// Initialize a new elevator view from a reference to an `Elevator`.  The car
+    /// calls are the distinct destination floors of the people on board, in
+    /// ascending order.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let my_elev: Elevator = Elevator::from(10_usize, 5.0_f64, 2.5_f64, 0.5_f64);
+    /// let my_view: ElevatorView = ElevatorView::from(&my_elev);
+    /// ```
+    pub fn from(elevator: &Elevator) -> ElevatorView {
+        //Get the distinct destination floors of the people on board
+        let mut car_calls: Vec<usize> = elevator.get_dest_floors();
+        car_calls.sort_unstable();
+        car_calls.dedup();
+
+        //Initialize the elevator view
+        ElevatorView {
+            floor_on: elevator.floor_on,
+            moving_up: elevator.moving_up,
+            stopped: elevator.stopped,
+            num_people: elevator.get_num_people(),
+            capacity: elevator.capacity,
+            load: elevator.get_load(),
+            rated_load: elevator.rated_load,
+            car_calls: car_calls,
+            status: elevator.status,
+            sleeping: elevator.sleeping,
+            idle_steps: elevator.idle_steps
+        }
+    }
+
+    /// Calculate the free capacity for the elevator
+    pub fn get_free_capacity(&self) -> usize {
+        self.capacity - self.num_people
+    }
+
+    /// Determine whether the elevator is available to be dispatched by an
+    /// `ElevatorController`, that is, whether it is in service.
+    pub fn is_available(&self) -> bool {
+        self.status == ElevatorStatus::InService
+    }
+
+    /// Determine whether the elevator is idle, that is, stopped with no people on
+    /// board.
+    pub fn is_idle(&self) -> bool {
+        self.stopped && self.num_people == 0_usize
+    }
+
+    /// Determine whether anyone on the elevator is going to the given floor
+    pub fn are_people_going_to_floor(&self, floor_index: usize) -> bool {
+        self.car_calls.contains(&floor_index)
+    }
+
+    /// If there are car calls on the elevator, this returns the nearest car call
+    /// represented as a length-2 tuple of `usize`s.  The first element is the
+    /// destination floor, and the second is the distance to the floor.  If there are
+    /// no car calls, or the only car call is the current floor, it returns
+    /// `(0_usize, 0_usize)`.
+    pub fn get_nearest_dest_floor(&self) -> (usize, usize) {
+        //Initialize variables to track the nearest destination floor
+        //and the min distance between here and a destination floor
+        let mut nearest_dest_floor: usize = 0_usize;
+        let mut min_dest_floor_dist: usize = 0_usize;
+
+        //Calculate the distance between each car call and the current floor
+        for dest_floor_index in self.car_calls.iter() {
+            let dest_floor_dist: usize = self.floor_on.abs_diff(*dest_floor_index);
+            if dest_floor_dist == 0_usize {
+                continue;
+            }
+
+            //Check whether this is less than the current minimum, or if no
+            //minimum has been assigned yet (in which case it is 0_usize)
+            if min_dest_floor_dist == 0_usize || dest_floor_dist < min_dest_floor_dist {
+                min_dest_floor_dist = dest_floor_dist;
+                nearest_dest_floor = *dest_floor_index;
+            }
+        }
+
+        //Return the nearest destination floor
+        (nearest_dest_floor, min_dest_floor_dist)
+    }
+}
+
+/// # `FloorView` struct
+///
+/// A `FloorView` is a read-only snapshot of a `Floor`, as observed by an
+/// `ElevatorController` at the start of its decision.  It holds the number of people
+/// on the floor and waiting on it, and its hall calls, that is, whether anyone waiting
+/// on the floor is going up or down.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct FloorView {
+    pub num_people: usize,
+    pub num_people_waiting: usize,
+    pub capacity: usize,
+    pub hall_call_up: bool,
+    pub hall_call_down: bool,
+    pub dest_prob: f64,
+    pub name: String
+}
+
+/// # `FloorView` type implementation
+///
+/// The following functions are used by `ElevatorController` implementations to
+/// inspect a floor when deciding their commands.
+impl FloorView {
+    /// Initialize a new floor view from a reference to a `Floor` and the floor's
+    /// index in the building.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let my_floor: Floor = Floor::new(100_usize);
+    /// let my_view: FloorView = FloorView::from(&my_floor, 0_usize);
+    /// ```
+    pub fn from(floor: &Floor, floor_index: usize) -> FloorView {
+        //Determine the hall calls from the destination floors of the people waiting
+        let dest_floors: Vec<usize> = floor.get_dest_floors();
+        let hall_call_up: bool = dest_floors.iter().any(|dest_floor| *dest_floor > floor_index);
+        let hall_call_down: bool = dest_floors.iter().any(|dest_floor| *dest_floor < floor_index);
+
+        //Initialize the floor view
+        FloorView {
+            num_people: floor.get_num_people(),
+            num_people_waiting: floor.get_num_people_waiting(),
+            capacity: floor.capacity,
+            hall_call_up: hall_call_up,
+            hall_call_down: hall_call_down,
+            dest_prob: floor.dest_prob,
+            name: floor.name.clone()
+        }
+    }
+
+    /// Calculate the free capacity for the floor
+    pub fn get_free_capacity(&self) -> usize {
+        self.capacity - self.num_people
+    }
+
+    /// Determine whether anyone on the floor is waiting/not at their desired floor
+    pub fn are_people_waiting(&self) -> bool {
+        self.num_people_waiting > 0_usize
+    }
+}
+
+/// # `BuildingView` struct
+///
+/// A `BuildingView` is an immutable snapshot of a `Building`, passed to an
+/// `ElevatorController` so that it can decide its `ElevatorCommand`s without mutable
+/// access to the building.  It holds views of the building's elevators and floors,
+/// along with the time step, mode, entrances, running metrics, and the building's
+/// destination forecast, the probability that each floor becomes a destination floor
+/// for an elevator within the building's forecast horizon.  If the building has a
+/// demand forecaster, then the view also holds its demand forecast, the expected number
+/// of trips starting on each floor within the forecast horizon, which is otherwise
+/// empty.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct BuildingView {
+    pub time_step: usize,
+    pub mode: BuildingMode,
+    pub elevators: Vec<ElevatorView>,
+    pub floors: Vec<FloorView>,
+    pub entrances: Vec<Entrance>,
+    pub dest_forecast: Vec<f64>,
+    pub demand_forecast: Vec<f64>,
+    pub avg_energy: f64,
+    pub avg_wait_time: f64,
+    pub tot_tips: f64
+}
+
+/// # `BuildingView` type implementation
+///
+/// The following functions are used by `ElevatorController` implementations to
+/// inspect a building when deciding their commands.
+impl BuildingView {
+    /// Initialize a new building view from a reference to a `Building`.  Generally
+    /// this is done via the building's `get_view` function.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let my_building: Building = Building::from(
+    ///     4_usize,
+    ///     2_usize,
+    ///     0.5_f64,
+    ///     5.0_f64,
+    ///     2.5_f64,
+    ///     0.5_f64
+    /// );
+    /// let my_view: BuildingView = BuildingView::from(&my_building);
+    /// ```
+    pub fn from(building: &Building) -> BuildingView {
+        BuildingView {
+            time_step: building.time_step,
+            mode: building.mode,
+            elevators: building.elevators.iter().map(ElevatorView::from).collect(),
+            floors: building.floors.iter()
+                .enumerate()
+                .map(|(i, floor)| FloorView::from(floor, i))
+                .collect(),
+            entrances: building.get_entrances().to_vec(),
+            dest_forecast: building.get_dest_forecast().to_vec(),
+            demand_forecast: building.get_demand_forecast(building.forecast_horizon).unwrap_or_default(),
+            avg_energy: building.avg_energy,
+            avg_wait_time: building.avg_wait_time,
+            tot_tips: building.tot_tips
+        }
+    }
+
+    /// Get the number of floors in the building
+    pub fn get_num_floors(&self) -> usize {
+        self.floors.len()
+    }
+
+    /// Get the number of elevators in the building
+    pub fn get_num_elevators(&self) -> usize {
+        self.elevators.len()
+    }
+
+    /// Determines whether there are any people waiting on a given floor.
+    pub fn are_people_waiting_on_floor(&self, floor_index: usize) -> bool {
+        self.floors[floor_index].are_people_waiting()
+    }
+
+    /// Determines the nearest floor at which people are waiting with respect to the
+    /// given floor.  Returns a tuple of usizes representing the floor index and the
+    /// distance to the floor, or `(0_usize, 0_usize)` if no other floor has people
+    /// waiting.
+    pub fn get_nearest_wait_floor(&self, floor_on: usize) -> (usize, usize) {
+        //Initialize variables to track the nearest waiting floor and
+        //the min distance between here and that floor
+        let mut nearest_wait_floor: usize = 0_usize;
+        let mut min_wait_floor_dist: usize = 0_usize;
+
+        //Loop through the floors and find the minimum distance floor
+        //with waiting people
+        for (i, floor) in self.floors.iter().enumerate() {
+            //Check if there is anyone waiting on the floor, if not
+            //then continue
+            if !floor.are_people_waiting() {
+                continue;
+            }
+
+            //Check whether the distance to the floor is less than the current
+            //minimum, or if no minimum has been assigned yet (in which case
+            //it is 0_usize)
+            let wait_floor_dist: usize = floor_on.abs_diff(i);
+            if min_wait_floor_dist == 0_usize || wait_floor_dist < min_wait_floor_dist {
+                min_wait_floor_dist = wait_floor_dist;
+                nearest_wait_floor = i;
+            }
+        }
+
+        //Return the nearest waiting floor
+        (nearest_wait_floor, min_wait_floor_dist)
+    }
+}
\ No newline at end of file
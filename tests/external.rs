@@ -0,0 +1,78 @@
+//Import standard/imported modules
+use std::time::Duration;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+//Import source modules
+use elevate_lib::building::Building;
+use elevate_lib::command::ElevatorCommand;
+use elevate_lib::controller::ElevatorController;
+use elevate_lib::external::ExternalController;
+
+//Initialize a small building for the external controller to run
+fn small_building() -> Building {
+    Building::from(
+        6_usize,
+        2_usize,
+        1.0_f64,
+        20_usize,
+        10_usize,
+        5.0_f64,
+        2.5_f64,
+        0.5_f64
+    )
+}
+
+#[test]
+fn nearest_controller_script_responds_every_time_step() {
+    //Launch the nearest controller script
+    let script: String = format!("{}/scripts/nearest_controller.py", env!("CARGO_MANIFEST_DIR"));
+    let mut controller: ExternalController = ExternalController::from(
+        "python3",
+        &[script.as_str()],
+        Duration::from_secs(5_u64)
+    ).unwrap();
+
+    //Run the building, checking that every request is answered
+    let mut building: Building = small_building();
+    let mut rng: StdRng = StdRng::seed_from_u64(0_u64);
+    for _ in 0_usize..50_usize {
+        building.begin_step(&mut rng);
+        let commands: Vec<ElevatorCommand> = controller.decide(&building.get_view());
+        assert_eq!(controller.last_error, None);
+        assert!(building.apply_elevator_commands(&commands).is_empty());
+        building.end_step().unwrap();
+    }
+}
+
+#[test]
+fn unresponsive_process_times_out() {
+    //Launch a process which never responds
+    let mut controller: ExternalController = ExternalController::from(
+        "sleep",
+        &["10"],
+        Duration::from_millis(100_u64)
+    ).unwrap();
+
+    //Check that the elevators are held and the timeout is reported
+    let building: Building = small_building();
+    let commands: Vec<ElevatorCommand> = controller.decide(&building.get_view());
+    assert!(commands.is_empty());
+    assert!(controller.last_error.as_deref().unwrap_or("").contains("timed out"));
+}
+
+#[test]
+fn responses_for_other_time_steps_are_discarded() {
+    //Launch a process which always responds for the wrong time step
+    let mut controller: ExternalController = ExternalController::from(
+        "sh",
+        &["-c", "while read line; do echo '{\"time_step\": 999, \"targets\": [1, 1]}'; done"],
+        Duration::from_millis(200_u64)
+    ).unwrap();
+
+    //Check that the response is discarded and the request times out
+    let building: Building = small_building();
+    let commands: Vec<ElevatorCommand> = controller.decide(&building.get_view());
+    assert!(commands.is_empty());
+    assert!(controller.last_error.as_deref().unwrap_or("").contains("timed out"));
+}
\ No newline at end of file